@@ -12,7 +12,26 @@ async function choose_jff_file() {
              const filename = file.name;
              const content = await file.text();
 
-             wasm_exports.open_jff_file(js_object(content));
+             wasm_exports.open_jff_file(js_object(content), js_object(filename));
+         },
+         { capture: false, once: true }
+     );
+     element.click();
+};
+
+async function choose_compare_jff_file() {
+     const element = document.createElement("input");
+     element.type = "file";
+     element.style.display = "none";
+     element.accept = ".jff";
+     element.addEventListener(
+         "change",
+         async () => {
+             if (!element.files || !element.files.length) return;
+             const file = element.files[0];
+             const content = await file.text();
+
+             wasm_exports.compare_jff_file(js_object(content));
          },
          { capture: false, once: true }
      );
@@ -41,6 +60,10 @@ async function save_jff_file(js_object) {
     }
 }
 
+function set_page_title(js_object) {
+    document.title = consume_js_object(js_object) + " - Sugarcubes";
+}
+
 async function choose_multiple_run_file() {
      const element = document.createElement("input");
      element.type = "file";
@@ -63,7 +86,9 @@ async function choose_multiple_run_file() {
 register_plugin = function (importObject) {
     importObject.env.choose_multiple_run_file = choose_multiple_run_file;
     importObject.env.choose_jff_file = choose_jff_file;
+    importObject.env.choose_compare_jff_file = choose_compare_jff_file;
     importObject.env.save_jff_file = save_jff_file;
+    importObject.env.set_page_title = set_page_title;
 }
 
 miniquad_add_plugin({
@@ -0,0 +1,123 @@
+//! Checks strings against a finite automaton from a JFLAP .jff file, without the GUI.
+//!
+//! Each line of input is a string, optionally followed by whitespace and the expected result,
+//! "accept" or "reject". A string of λ stands for the empty string, and blank lines are skipped.
+//! The exit code is 1 if any result differs from the expected one, and 2 on other errors.
+
+use sugarcubes_core::{
+    automata::SimulateAutomaton,
+    grammar::EMPTY_STRING_SYMBOL,
+    jff::{read_jff, JffAutomaton, JffDocument},
+};
+
+use std::{
+    fs,
+    io::{self, BufRead, BufReader},
+    process,
+};
+
+const USAGE: &str = "Usage: sugarcubes-cli <automaton.jff> [strings file]
+
+Reads strings from the file, or from standard input if it is omitted or \"-\",
+and prints whether the automaton accepts each one.";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.is_empty() || args.len() > 2 || args[0] == "-h" || args[0] == "--help" {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    match run(&args[0], args.get(1).map(String::as_str)) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(message) => {
+            eprintln!("error: {}", message);
+            process::exit(2);
+        }
+    }
+}
+
+// Check every string, returning whether they all matched their expected results
+fn run(jff_path: &str, strings_path: Option<&str>) -> Result<bool, String> {
+    let content = fs::read_to_string(jff_path)
+        .map_err(|error| format!("could not read {}: {}", jff_path, error))?;
+    let (document, warnings) =
+        read_jff(&content).map_err(|error| format!("could not open {}: {}", jff_path, error))?;
+    for warning in warnings {
+        eprintln!("warning: {}: {}", jff_path, warning);
+    }
+    let fa = match document {
+        JffDocument::Automaton {
+            automaton: JffAutomaton::FiniteAutomaton(fa),
+            ..
+        } => fa,
+        _ => return Err(format!("{} is not a finite automaton", jff_path)),
+    };
+
+    let reader: Box<dyn BufRead> = match strings_path {
+        None | Some("-") => Box::new(BufReader::new(io::stdin())),
+        Some(path) => {
+            Box::new(BufReader::new(fs::File::open(path).map_err(|error| {
+                format!("could not read {}: {}", path, error)
+            })?))
+        }
+    };
+
+    let mut all_matched = true;
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(|error| error.to_string())?;
+        let mut columns = line.split_whitespace();
+        let string = match columns.next() {
+            Some(string) => string,
+            None => continue,
+        };
+        let expected = match columns.next() {
+            Some(column) => Some(parse_expected(column).ok_or_else(|| {
+                format!(
+                    "line {}: expected \"accept\" or \"reject\", found \"{}\"",
+                    i + 1,
+                    column
+                )
+            })?),
+            None => None,
+        };
+
+        let input = if string == EMPTY_STRING_SYMBOL.to_string() {
+            ""
+        } else {
+            string
+        };
+        let accepted = fa.check_input(input);
+        match expected {
+            Some(expected) if expected != accepted => {
+                all_matched = false;
+                println!(
+                    "{}\t{}\tMISMATCH (expected {})",
+                    string,
+                    result_name(accepted),
+                    result_name(expected)
+                );
+            }
+            _ => println!("{}\t{}", string, result_name(accepted)),
+        }
+    }
+
+    Ok(all_matched)
+}
+
+fn parse_expected(column: &str) -> Option<bool> {
+    match column.to_lowercase().as_str() {
+        "accept" => Some(true),
+        "reject" => Some(false),
+        _ => None,
+    }
+}
+
+fn result_name(accepted: bool) -> &'static str {
+    if accepted {
+        "accept"
+    } else {
+        "reject"
+    }
+}
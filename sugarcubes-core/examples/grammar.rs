@@ -0,0 +1,179 @@
+use sugarcubes_core::automata::SimulateAutomaton;
+use sugarcubes_core::grammar::{
+    DerivationLimits, DerivationResult, Grammar, LlParseTable, Production, SlrParseTable,
+    DERIVES_SYMBOL,
+};
+
+fn main() {
+    let grammars = vec![
+        // Strings of a's followed by b's
+        vec![("S", "aS"), ("S", "B"), ("B", "bB"), ("B", "")],
+        // The same language, generated from the right end
+        vec![("S", "Sb"), ("S", "A"), ("A", "Aa"), ("A", "")],
+        // a^n b^n
+        vec![("S", "aSb"), ("S", "")],
+        // a^n b^n c^n, for n at least 1
+        vec![
+            ("S", "aSBC"),
+            ("S", "aBC"),
+            ("CB", "BC"),
+            ("aB", "ab"),
+            ("bB", "bb"),
+            ("bC", "bc"),
+            ("cC", "cc"),
+        ],
+        // A production which erases part of the string
+        vec![("S", "aSA"), ("aA", "")],
+    ];
+
+    for productions in grammars {
+        let grammar = Grammar::new(
+            productions
+                .into_iter()
+                .map(|(lhs, rhs)| Production::new(lhs, rhs))
+                .collect(),
+            'S',
+        );
+        print!("{}", grammar);
+        println!(
+            "Variables {:?}, terminals {:?}: {}\n",
+            grammar.variables(),
+            grammar.terminals(),
+            grammar.classify()
+        );
+    }
+
+    // a^n b^n for n at least 1, in Chomsky normal form
+    let cnf = Grammar::new(
+        vec![
+            Production::new("S", "AB"),
+            Production::new("S", "AC"),
+            Production::new("C", "SB"),
+            Production::new("A", "a"),
+            Production::new("B", "b"),
+        ],
+        'S',
+    );
+    for input in &["aabb", "abab"] {
+        let table = cnf.cyk(input).unwrap();
+        match table.parse_tree() {
+            Some(tree) => println!("CYK {:?}: accepted, parsed as {}", input, tree),
+            None => println!("CYK {:?}: rejected", input),
+        }
+    }
+
+    // Convert a^n b^n, including the empty string, to Chomsky normal form
+    let grammar = Grammar::new(
+        vec![Production::new("S", "aSb"), Production::new("S", "")],
+        'S',
+    );
+    for step in grammar.chomsky_normal_form_steps().unwrap() {
+        print!("\n{}:\n{}", step.kind, step.grammar);
+    }
+
+    // Left recursive arithmetic expressions, in Greibach normal form
+    let grammar = Grammar::new(
+        vec![
+            Production::new("S", "S+T"),
+            Production::new("S", "T"),
+            Production::new("T", "T*F"),
+            Production::new("T", "F"),
+            Production::new("F", "(S)"),
+            Production::new("F", "a"),
+        ],
+        'S',
+    );
+    let conversion = grammar.greibach_normal_form().unwrap();
+    println!();
+    for rewrite in &conversion.log {
+        println!("{}", rewrite);
+    }
+    print!("{}", conversion.grammar);
+
+    // An LL(1) grammar for a^n b^n, traced while parsing
+    let grammar = Grammar::new(
+        vec![Production::new("S", "aSb"), Production::new("S", "")],
+        'S',
+    );
+    let table = LlParseTable::new(&grammar).unwrap();
+    println!(
+        "\nFIRST {:?}, FOLLOW {:?}",
+        table.first_sets(),
+        table.follow_sets()
+    );
+    for step in table.parse("aabb").unwrap() {
+        println!("{:>6} {:>6}  {}", step.stack, step.remaining, step.action);
+    }
+
+    // The left recursive expression grammar is not LL(1), but is SLR(1)
+    let grammar = Grammar::new(
+        vec![
+            Production::new("S", "S+T"),
+            Production::new("S", "T"),
+            Production::new("T", "T*F"),
+            Production::new("T", "F"),
+            Production::new("F", "(S)"),
+            Production::new("F", "a"),
+        ],
+        'S',
+    );
+    let table = SlrParseTable::new(&grammar).unwrap();
+    println!(
+        "\nLL(1): {}, SLR(1): {}, {} item sets",
+        grammar.is_ll1(),
+        grammar.is_slr1(),
+        table.item_sets().len()
+    );
+    for step in table.parse("a+a*a").unwrap() {
+        println!("{:<24} {:>7}  {}", step.stack, step.remaining, step.action);
+    }
+
+    // Brute force derivations in the unrestricted grammar for a^n b^n c^n
+    let grammar = Grammar::new(
+        vec![
+            Production::new("S", "aSBC"),
+            Production::new("S", "aBC"),
+            Production::new("CB", "BC"),
+            Production::new("aB", "ab"),
+            Production::new("bB", "bb"),
+            Production::new("bC", "bc"),
+            Production::new("cC", "cc"),
+        ],
+        'S',
+    );
+    println!();
+    for input in &["aabbcc", "aabbc"] {
+        match grammar.brute_force_derivation(input, DerivationLimits::default()) {
+            DerivationResult::Derived(derivation) => {
+                println!(
+                    "{:?}: {}",
+                    input,
+                    derivation.join(&format!(" {} ", DERIVES_SYMBOL))
+                )
+            }
+            result => println!("{:?}: {:?}", input, result),
+        }
+    }
+
+    // Convert the right-linear grammar for a's followed by b's to a finite automaton and back,
+    // naming each state after its variable
+    let grammar = Grammar::new(
+        vec![
+            Production::new("S", "aS"),
+            Production::new("S", "B"),
+            Production::new("B", "bB"),
+            Production::new("B", ""),
+        ],
+        'S',
+    );
+    let (fa, variables) = grammar.to_finite_automaton().unwrap();
+    println!();
+    for input in &["aabb", "ba"] {
+        println!("{:?} by finite automaton: {}", input, fa.check_input(input));
+    }
+    let names = variables
+        .iter()
+        .map(|(&state, variable)| (state, variable.to_string()))
+        .collect();
+    print!("Back to a grammar:\n{}", fa.to_grammar(&names).unwrap());
+}
@@ -0,0 +1,62 @@
+use sugarcubes_core::automata::{
+    finite_automaton::FiniteAutomatonTransition,
+    mealy_machine::{MealyMachine, MealyMachineTransition},
+    moore_machine::MooreMachine,
+    Configuration,
+};
+
+fn main() {
+    // Create a Mealy machine which outputs 1 whenever a symbol repeats the one before it
+    let mut mealy = MealyMachine::default();
+    let start = mealy.automaton.add_new_state();
+    mealy.automaton.set_initial(start);
+    let last_a = mealy.automaton.add_new_state();
+    let last_b = mealy.automaton.add_new_state();
+
+    for &(from, to, symbol, output) in &[
+        (start, last_a, 'a', "0"),
+        (start, last_b, 'b', "0"),
+        (last_a, last_a, 'a', "1"),
+        (last_a, last_b, 'b', "0"),
+        (last_b, last_a, 'a', "0"),
+        (last_b, last_b, 'b', "1"),
+    ] {
+        mealy
+            .automaton
+            .add_transition(MealyMachineTransition::new(from, to, symbol, output));
+    }
+
+    for input in &["", "aab", "abba", "abc"] {
+        match mealy.run(input) {
+            Some((output, _)) => println!("Mealy {:?}: {:?}", input, output),
+            None => println!("Mealy {:?}: stuck", input),
+        }
+    }
+
+    // Create a Moore machine which outputs the count of a's so far, modulo 3
+    let mut moore = MooreMachine::default();
+    let counts: Vec<u32> = (0..3).map(|_| moore.automaton.add_new_state()).collect();
+    moore.automaton.set_initial(counts[0]);
+    for (i, &state) in counts.iter().enumerate() {
+        moore.set_output(state, &i.to_string());
+        let next = counts[(i + 1) % 3];
+        moore
+            .automaton
+            .add_transition(FiniteAutomatonTransition::new(state, next, 'a'));
+        moore
+            .automaton
+            .add_transition(FiniteAutomatonTransition::new(state, state, 'b'));
+    }
+
+    for input in &["", "aaaa", "abab"] {
+        match moore.run(input) {
+            Some((output, configuration)) => println!(
+                "Moore {:?}: {:?}, ending in state {}",
+                input,
+                output,
+                configuration.state()
+            ),
+            None => println!("Moore {:?}: stuck", input),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use sugarcubes_core::automata::{
+    pushdown_automaton::{Acceptance, PushdownAutomaton, PushdownAutomatonTransition},
+    SimulateAutomaton, EMPTY_STRING,
+};
+use sugarcubes_core::grammar::{Grammar, Production, PushdownConstruction};
+
+fn main() {
+    // Create a PDA for strings of the form a^n b^n,
+    // counting a's by pushing them onto the stack
+    let mut pda = PushdownAutomaton::default();
+    let state0 = pda.automaton.add_new_state();
+    pda.automaton.set_initial(state0);
+    let state1 = pda.automaton.add_new_state();
+    let state2 = pda.automaton.add_new_state();
+    pda.automaton.set_final(state2, true);
+
+    pda.automaton
+        .add_transition(PushdownAutomatonTransition::new(
+            state0, state0, 'a', "", "A",
+        ));
+    pda.automaton
+        .add_transition(PushdownAutomatonTransition::new(
+            state0,
+            state1,
+            EMPTY_STRING,
+            "",
+            "",
+        ));
+    pda.automaton
+        .add_transition(PushdownAutomatonTransition::new(
+            state1, state1, 'b', "A", "",
+        ));
+    pda.automaton
+        .add_transition(PushdownAutomatonTransition::new(
+            state1,
+            state2,
+            EMPTY_STRING,
+            "Z",
+            "",
+        ));
+
+    for input in &["", "ab", "aabb", "aab", "abab"] {
+        println!("{:?}: {}", input, pda.check_input(input));
+    }
+
+    // Popping the initial stack symbol empties the stack, so the same strings are accepted
+    pda.acceptance = Acceptance::EmptyStack;
+    for input in &["", "ab", "aabb", "aab", "abab"] {
+        println!("{:?} by empty stack: {}", input, pda.check_input(input));
+    }
+
+    // The triple construction gives a grammar for the same language
+    let grammar = pda.to_grammar().unwrap();
+    println!("\nGrammar from the triple construction:\n{}", grammar);
+
+    // Build pushdown automata for a grammar of nonempty balanced parentheses, both ways
+    let grammar = Grammar::new(
+        ["()", "(S)", "()S", "(S)S"]
+            .iter()
+            .map(|rhs| Production::new("S", rhs))
+            .collect(),
+        'S',
+    );
+    for &construction in &[PushdownConstruction::Ll, PushdownConstruction::Lr] {
+        let pda = grammar.to_pushdown_automaton(construction).unwrap();
+        for input in &["()", "(())()", "(()", ")("] {
+            println!(
+                "{:?} by {} PDA: {}",
+                input,
+                construction,
+                pda.check_input(input)
+            );
+        }
+    }
+}
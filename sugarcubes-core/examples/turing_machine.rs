@@ -0,0 +1,118 @@
+use sugarcubes_core::automata::turing_machine::{
+    Acceptance, Direction, TapeAction, TuringMachine, TuringMachineResult, TuringMachineTransition,
+    BLANK_SYMBOL,
+};
+
+fn main() {
+    // Create a machine deciding whether a string of a's has even length,
+    // with an explicit reject state for odd lengths
+    let mut tm = TuringMachine::default();
+    let even = tm.automaton.add_new_state();
+    tm.automaton.set_initial(even);
+    let odd = tm.automaton.add_new_state();
+    let accept = tm.automaton.add_new_state();
+    tm.automaton.set_final(accept, true);
+    let reject = tm.automaton.add_new_state();
+    tm.set_reject(reject, true);
+
+    tm.automaton.add_transition(TuringMachineTransition::new(
+        even,
+        odd,
+        'a',
+        'a',
+        Direction::Right,
+    ));
+    tm.automaton.add_transition(TuringMachineTransition::new(
+        odd,
+        even,
+        'a',
+        'a',
+        Direction::Right,
+    ));
+    tm.automaton.add_transition(TuringMachineTransition::new(
+        even,
+        accept,
+        BLANK_SYMBOL,
+        BLANK_SYMBOL,
+        Direction::Stay,
+    ));
+    tm.automaton.add_transition(TuringMachineTransition::new(
+        odd,
+        reject,
+        BLANK_SYMBOL,
+        BLANK_SYMBOL,
+        Direction::Stay,
+    ));
+
+    for input in &["", "a", "aa", "aaa", "ab"] {
+        println!("{:?}: {}", input, describe(&tm.run(input)));
+    }
+
+    // Looping on a blank instead of accepting makes the machine run forever on even lengths
+    tm.automaton.remove_transition(TuringMachineTransition::new(
+        even,
+        accept,
+        BLANK_SYMBOL,
+        BLANK_SYMBOL,
+        Direction::Stay,
+    ));
+    tm.automaton.add_transition(TuringMachineTransition::new(
+        even,
+        even,
+        BLANK_SYMBOL,
+        BLANK_SYMBOL,
+        Direction::Right,
+    ));
+    tm.acceptance = Acceptance::Halting;
+    tm.max_steps = 100;
+    for input in &["aa", "ab"] {
+        println!("{:?} with a loop: {}", input, describe(&tm.run(input)));
+    }
+
+    // Create a two-tape machine that copies its input onto the second tape
+    let mut copier = TuringMachine::with_tapes(2);
+    let copying = copier.automaton.add_new_state();
+    copier.automaton.set_initial(copying);
+    let done = copier.automaton.add_new_state();
+    copier.automaton.set_final(done, true);
+
+    for &symbol in &['a', 'b'] {
+        copier
+            .automaton
+            .add_transition(TuringMachineTransition::with_actions(
+                copying,
+                copying,
+                vec![
+                    TapeAction::new(symbol, symbol, Direction::Right),
+                    TapeAction::new(BLANK_SYMBOL, symbol, Direction::Right),
+                ],
+            ));
+    }
+    copier
+        .automaton
+        .add_transition(TuringMachineTransition::with_actions(
+            copying,
+            done,
+            vec![
+                TapeAction::new(BLANK_SYMBOL, BLANK_SYMBOL, Direction::Stay),
+                TapeAction::new(BLANK_SYMBOL, BLANK_SYMBOL, Direction::Stay),
+            ],
+        ));
+
+    if let TuringMachineResult::Accepted(configuration) = copier.run("abba") {
+        println!("Copied \"{}\"", configuration.tapes[1].contents());
+    }
+}
+
+fn describe(result: &TuringMachineResult) -> String {
+    match result {
+        TuringMachineResult::Accepted(configuration) => {
+            format!("accepted with tape {}", configuration.tapes[0])
+        }
+        TuringMachineResult::Rejected(configuration) => {
+            format!("rejected with tape {}", configuration.tapes[0])
+        }
+        TuringMachineResult::HaltedWithoutAccepting => "halted without accepting".to_string(),
+        TuringMachineResult::DidNotHalt(steps) => format!("did not halt within {} steps", steps),
+    }
+}
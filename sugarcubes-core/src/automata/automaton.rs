@@ -54,7 +54,7 @@ impl<T: Transition> Automaton<T> {
     }
 
     /// Returns a sorted iterator of the states of the automaton
-    pub fn states_iter(&self) -> btree_map::Keys<u32, State> {
+    pub fn states_iter(&self) -> btree_map::Keys<'_, u32, State> {
         self.states.keys()
     }
 
@@ -0,0 +1,75 @@
+use super::{FiniteAutomaton, FiniteAutomatonTransition};
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+impl FiniteAutomaton {
+    /// Builds an equivalent deterministic automaton using the subset construction.
+    ///
+    /// Each state of the result corresponds to an ε-closed set of states in this automaton,
+    /// and the returned map gives that set for every new state ID.
+    /// Only subsets reachable from the initial state are created,
+    /// and no trap state is added for the empty subset.
+    /// Transitions reading more than one symbol are first split into chains of new states,
+    /// which may then appear in the sets.
+    pub fn determinize(&self) -> (FiniteAutomaton, BTreeMap<u32, BTreeSet<u32>>) {
+        if !self.reads_single_symbols() {
+            return self.split_transitions().determinize();
+        }
+
+        let mut dfa = FiniteAutomaton::default();
+        let mut subsets = BTreeMap::new();
+
+        let initial = match self.automaton.initial() {
+            Some(initial) => initial,
+            None => return (dfa, subsets),
+        };
+        let alphabet = self.alphabet();
+
+        // Maps each discovered subset to the ID of the state representing it
+        let mut ids: HashMap<BTreeSet<u32>, u32> = HashMap::new();
+        let mut queue = VecDeque::new();
+
+        let initial_subset = self.epsilon_closure(&[initial].iter().cloned().collect());
+        let initial_id = self.add_subset_state(&mut dfa, &initial_subset);
+        dfa.automaton.set_initial(initial_id);
+        ids.insert(initial_subset.clone(), initial_id);
+        queue.push_back(initial_subset);
+
+        while let Some(subset) = queue.pop_front() {
+            let from = ids[&subset];
+            for &symbol in &alphabet {
+                let next = self.epsilon_closure(&self.states_after_symbol(&subset, symbol));
+                if next.is_empty() {
+                    continue;
+                }
+
+                let to = if let Some(&id) = ids.get(&next) {
+                    id
+                } else {
+                    let id = self.add_subset_state(&mut dfa, &next);
+                    ids.insert(next.clone(), id);
+                    queue.push_back(next);
+                    id
+                };
+                dfa.automaton
+                    .add_transition(FiniteAutomatonTransition::new(from, to, symbol));
+            }
+        }
+
+        for (subset, id) in ids {
+            subsets.insert(id, subset);
+        }
+
+        (dfa, subsets)
+    }
+
+    // Adds a state representing a subset of this automaton's states,
+    // which is final if any state in the subset is final
+    fn add_subset_state(&self, dfa: &mut FiniteAutomaton, subset: &BTreeSet<u32>) -> u32 {
+        let id = dfa.automaton.add_new_state();
+        if subset.iter().any(|&state| self.automaton.is_final(state)) {
+            dfa.automaton.set_final(id, true);
+        }
+        id
+    }
+}
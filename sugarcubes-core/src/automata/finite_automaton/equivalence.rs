@@ -0,0 +1,75 @@
+use super::FiniteAutomaton;
+
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+/// The result of comparing the languages accepted by two automata
+#[derive(Clone, Debug, PartialEq)]
+pub enum Equivalence {
+    Equivalent,
+    /// A shortest string accepted by exactly one of the automata,
+    /// and whether it is accepted by the first automaton rather than the second
+    Counterexample {
+        string: String,
+        accepted_by_first: bool,
+    },
+}
+
+impl FiniteAutomaton {
+    /// Checks whether two automata accept the same language,
+    /// finding a shortest counterexample if they do not.
+    ///
+    /// Neither automaton needs to be deterministic, since the search runs
+    /// over pairs of ε-closed sets of states, one from each automaton.
+    pub fn equivalence(&self, other: &FiniteAutomaton) -> Equivalence {
+        if !self.reads_single_symbols() || !other.reads_single_symbols() {
+            return self
+                .split_transitions()
+                .equivalence(&other.split_transitions());
+        }
+
+        let alphabet: BTreeSet<char> = self.alphabet().union(&other.alphabet()).cloned().collect();
+
+        let start = (self.initial_closure(), other.initial_closure());
+        let mut visited = HashSet::new();
+        visited.insert(start.clone());
+        let mut queue = VecDeque::new();
+        queue.push_back((start, String::new()));
+
+        // Breadth-first search, so the first difference found is for a shortest string
+        while let Some(((states, other_states), string)) = queue.pop_front() {
+            let accepted_by_first = self.any_final(&states);
+            if accepted_by_first != other.any_final(&other_states) {
+                return Equivalence::Counterexample {
+                    string,
+                    accepted_by_first,
+                };
+            }
+
+            for &symbol in &alphabet {
+                let next = (
+                    self.epsilon_closure(&self.states_after_symbol(&states, symbol)),
+                    other.epsilon_closure(&other.states_after_symbol(&other_states, symbol)),
+                );
+                if visited.insert(next.clone()) {
+                    let mut next_string = string.clone();
+                    next_string.push(symbol);
+                    queue.push_back((next, next_string));
+                }
+            }
+        }
+
+        Equivalence::Equivalent
+    }
+
+    // The ε-closure of the initial state, or the empty set if there is no initial state
+    fn initial_closure(&self) -> BTreeSet<u32> {
+        match self.automaton.initial() {
+            Some(initial) => self.epsilon_closure(&[initial].iter().cloned().collect()),
+            None => BTreeSet::new(),
+        }
+    }
+
+    fn any_final(&self, states: &BTreeSet<u32>) -> bool {
+        states.iter().any(|&state| self.automaton.is_final(state))
+    }
+}
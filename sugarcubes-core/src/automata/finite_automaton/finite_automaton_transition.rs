@@ -1,11 +1,12 @@
-use crate::automata::Transition;
+use crate::automata::{Transition, EMPTY_STRING};
 
-/// A transition between two states in a finite automaton
-#[derive(Default, PartialEq, Clone, Copy)]
+/// A transition between two states in a finite automaton,
+/// which reads a string of any length, including the empty string
+#[derive(Default, PartialEq, Clone)]
 pub struct FiniteAutomatonTransition {
     from: u32,
     to: u32,
-    symbol: char,
+    read: String,
 }
 
 impl Transition for FiniteAutomatonTransition {
@@ -19,11 +20,36 @@ impl Transition for FiniteAutomatonTransition {
 }
 
 impl FiniteAutomatonTransition {
+    /// Creates a transition reading a single symbol, or nothing if the symbol is EMPTY_STRING
     pub fn new(from: u32, to: u32, symbol: char) -> Self {
-        Self { from, to, symbol }
+        let read = if symbol == EMPTY_STRING {
+            String::new()
+        } else {
+            symbol.to_string()
+        };
+        Self { from, to, read }
     }
 
-    pub fn symbol(&self) -> char {
-        self.symbol
+    /// Creates a transition reading a whole string at once
+    pub fn with_read(from: u32, to: u32, read: &str) -> Self {
+        Self {
+            from,
+            to,
+            read: read.to_string(),
+        }
+    }
+
+    /// Returns the string read by the transition, which is empty for an ε-transition
+    pub fn read(&self) -> &str {
+        &self.read
+    }
+
+    /// Returns the symbol read by the transition, if it reads exactly one
+    pub fn symbol(&self) -> Option<char> {
+        let mut chars = self.read.chars();
+        match (chars.next(), chars.next()) {
+            (Some(symbol), None) => Some(symbol),
+            _ => None,
+        }
     }
 }
@@ -0,0 +1,307 @@
+use super::{FiniteAutomaton, FiniteAutomatonTransition};
+use crate::automata::Transition;
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/// A partition of the reachable states of a deterministic automaton
+/// into groups of states that have not yet been distinguished.
+///
+/// Missing transitions are treated as going to an implicit trap state,
+/// which takes part in the partition but is never part of the minimized automaton.
+pub struct StatePartition {
+    alphabet: BTreeSet<char>,
+    initial: Option<u32>,
+    final_states: BTreeSet<u32>,
+    // The completed transition function over the reachable states and the trap state
+    transitions: BTreeMap<(u32, char), u32>,
+    trap: Option<u32>,
+    groups: Vec<BTreeSet<u32>>,
+}
+
+impl StatePartition {
+    /// Creates the initial partition of final and non-final states,
+    /// or returns None if the automaton is not deterministic
+    pub fn new(fa: &FiniteAutomaton) -> Option<Self> {
+        if !fa.is_deterministic() {
+            return None;
+        }
+
+        let alphabet = fa.alphabet();
+        let reachable = fa.reachable_states();
+        let trap_id = fa
+            .automaton
+            .states_iter()
+            .last()
+            .map_or(0, |&last| last + 1);
+        let mut trap = None;
+
+        let mut transitions = BTreeMap::new();
+        for &state in &reachable {
+            for &symbol in &alphabet {
+                let to = fa
+                    .automaton
+                    .transitions_from(state)
+                    .into_iter()
+                    .find(|transition| transition.symbol() == Some(symbol))
+                    .map(|transition| transition.to());
+                let to = to.unwrap_or_else(|| *trap.get_or_insert(trap_id));
+                transitions.insert((state, symbol), to);
+            }
+        }
+        if let Some(trap) = trap {
+            for &symbol in &alphabet {
+                transitions.insert((trap, symbol), trap);
+            }
+        }
+
+        let (finals, non_finals): (BTreeSet<u32>, BTreeSet<u32>) = reachable
+            .iter()
+            .cloned()
+            .chain(trap)
+            .partition(|&state| fa.automaton.is_final(state));
+        let groups = vec![finals.clone(), non_finals]
+            .into_iter()
+            .filter(|group| !group.is_empty())
+            .collect();
+
+        Some(Self {
+            alphabet,
+            initial: fa.automaton.initial(),
+            final_states: finals,
+            transitions,
+            trap,
+            groups,
+        })
+    }
+
+    /// Returns the groups of states that are not yet known to be distinguishable
+    pub fn groups(&self) -> &[BTreeSet<u32>] {
+        &self.groups
+    }
+
+    /// Returns the ID used for the implicit trap state, if the automaton needed one
+    pub fn trap_state(&self) -> Option<u32> {
+        self.trap
+    }
+
+    pub fn alphabet(&self) -> &BTreeSet<char> {
+        &self.alphabet
+    }
+
+    /// Splits a group by which groups its states go to on a given symbol,
+    /// returning whether the symbol distinguished any of its states
+    pub fn split_group_on(&mut self, index: usize, symbol: char) -> bool {
+        let mut subgroups: BTreeMap<usize, BTreeSet<u32>> = BTreeMap::new();
+        for &state in &self.groups[index] {
+            let target = self.group_of(self.transitions[&(state, symbol)]);
+            subgroups.entry(target).or_default().insert(state);
+        }
+
+        if subgroups.len() < 2 {
+            return false;
+        }
+
+        let mut subgroups = subgroups.into_values();
+        self.groups[index] = subgroups.next().unwrap();
+        self.groups.extend(subgroups);
+        true
+    }
+
+    /// Splits a group on the first symbol that distinguishes any of its states,
+    /// returning that symbol, or None if the group cannot be split
+    pub fn split_group(&mut self, index: usize) -> Option<char> {
+        let symbol = self.distinguishing_symbol(index)?;
+        self.split_group_on(index, symbol);
+        Some(symbol)
+    }
+
+    /// Returns the first symbol that distinguishes some states of a group, if any
+    pub fn distinguishing_symbol(&self, index: usize) -> Option<char> {
+        self.alphabet.iter().cloned().find(|&symbol| {
+            let mut targets = self.groups[index]
+                .iter()
+                .map(|&state| self.group_of(self.transitions[&(state, symbol)]));
+            let first = targets.next();
+            targets.any(|target| Some(target) != first)
+        })
+    }
+
+    /// Returns whether no group can be split any further
+    pub fn is_complete(&self) -> bool {
+        (0..self.groups.len()).all(|index| self.distinguishing_symbol(index).is_none())
+    }
+
+    /// Splits groups one symbol at a time until the partition is complete
+    pub fn complete(&mut self) {
+        while let Some(index) =
+            (0..self.groups.len()).find(|&index| self.distinguishing_symbol(index).is_some())
+        {
+            self.split_group(index);
+        }
+    }
+
+    /// Refines the partition to completion using Hopcroft's algorithm
+    pub fn refine(&mut self) {
+        let mut predecessors: BTreeMap<(u32, char), Vec<u32>> = BTreeMap::new();
+        for (&(from, symbol), &to) in &self.transitions {
+            predecessors.entry((to, symbol)).or_default().push(from);
+        }
+
+        let mut worklist: VecDeque<BTreeSet<u32>> = self.groups.iter().cloned().collect();
+        while let Some(splitter) = worklist.pop_front() {
+            for &symbol in &self.alphabet {
+                // The states which go into the splitter on this symbol
+                let preimage: BTreeSet<u32> = splitter
+                    .iter()
+                    .filter_map(|&state| predecessors.get(&(state, symbol)))
+                    .flatten()
+                    .cloned()
+                    .collect();
+                if preimage.is_empty() {
+                    continue;
+                }
+
+                for index in 0..self.groups.len() {
+                    let (inside, outside): (BTreeSet<u32>, BTreeSet<u32>) = self.groups[index]
+                        .iter()
+                        .partition(|state| preimage.contains(state));
+                    if inside.is_empty() || outside.is_empty() {
+                        continue;
+                    }
+
+                    if let Some(position) = worklist
+                        .iter()
+                        .position(|group| *group == self.groups[index])
+                    {
+                        worklist.remove(position);
+                        worklist.push_back(inside.clone());
+                        worklist.push_back(outside.clone());
+                    } else if inside.len() <= outside.len() {
+                        worklist.push_back(inside.clone());
+                    } else {
+                        worklist.push_back(outside.clone());
+                    }
+
+                    self.groups[index] = inside;
+                    self.groups.push(outside);
+                }
+            }
+        }
+    }
+
+    /// Builds the automaton with one state per group, along with the original states in each,
+    /// or returns None if the partition is not yet complete
+    pub fn to_automaton(&self) -> Option<(FiniteAutomaton, BTreeMap<u32, BTreeSet<u32>>)> {
+        if !self.is_complete() {
+            return None;
+        }
+
+        let mut fa = FiniteAutomaton::default();
+        let mut merged = BTreeMap::new();
+
+        // Create states in order of their smallest original state,
+        // except that the initial group comes first, and the trap group is left out
+        let mut groups: Vec<(usize, BTreeSet<u32>)> = self
+            .groups
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(index, mut group)| {
+                if let Some(trap) = self.trap {
+                    group.remove(&trap);
+                }
+                (index, group)
+            })
+            .filter(|(_, group)| !group.is_empty())
+            .collect();
+        groups.sort_by_key(|(_, group)| {
+            (
+                !self.initial.is_some_and(|initial| group.contains(&initial)),
+                group.iter().next().cloned(),
+            )
+        });
+
+        let mut ids = BTreeMap::new();
+        for (index, group) in &groups {
+            let id = fa.automaton.add_new_state();
+            ids.insert(*index, id);
+            merged.insert(id, group.clone());
+        }
+
+        for (index, group) in &groups {
+            let from = ids[index];
+            let representative = *group.iter().next().unwrap();
+            if self.initial.is_some_and(|initial| group.contains(&initial)) {
+                fa.automaton.set_initial(from);
+            }
+            if self.final_states.contains(&representative) {
+                fa.automaton.set_final(from, true);
+            }
+
+            for &symbol in &self.alphabet {
+                let target = self.group_of(self.transitions[&(representative, symbol)]);
+                if let Some(&to) = ids.get(&target) {
+                    fa.automaton
+                        .add_transition(FiniteAutomatonTransition::new(from, to, symbol));
+                }
+            }
+        }
+
+        Some((fa, merged))
+    }
+
+    fn group_of(&self, state: u32) -> usize {
+        self.groups
+            .iter()
+            .position(|group| group.contains(&state))
+            .expect("state is not in the partition")
+    }
+}
+
+impl FiniteAutomaton {
+    /// Returns whether every transition reads exactly one symbol,
+    /// with at most one transition from each state on each symbol
+    pub fn is_deterministic(&self) -> bool {
+        self.automaton.states_iter().all(|&state| {
+            let mut symbols = BTreeSet::new();
+            self.automaton
+                .transitions_from(state)
+                .into_iter()
+                .all(|transition| {
+                    transition
+                        .symbol()
+                        .is_some_and(|symbol| symbols.insert(symbol))
+                })
+        })
+    }
+
+    /// Returns the states reachable from the initial state
+    pub fn reachable_states(&self) -> BTreeSet<u32> {
+        match self.automaton.initial() {
+            Some(initial) => {
+                let mut reachable: BTreeSet<u32> = [initial].iter().cloned().collect();
+                let mut stack = vec![initial];
+                while let Some(state) = stack.pop() {
+                    for transition in self.automaton.transitions_from(state) {
+                        if reachable.insert(transition.to()) {
+                            stack.push(transition.to());
+                        }
+                    }
+                }
+                reachable
+            }
+            None => BTreeSet::new(),
+        }
+    }
+
+    /// Builds the minimal deterministic automaton accepting the same language,
+    /// along with the original states merged into each new state.
+    ///
+    /// Unreachable states are removed, and do not appear in the returned map.
+    /// Returns None if the automaton is not deterministic.
+    pub fn minimize(&self) -> Option<(FiniteAutomaton, BTreeMap<u32, BTreeSet<u32>>)> {
+        let mut partition = StatePartition::new(self)?;
+        partition.refine();
+        partition.to_automaton()
+    }
+}
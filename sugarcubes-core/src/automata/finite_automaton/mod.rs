@@ -1,10 +1,17 @@
-use crate::automata::{Automaton, Configuration, SimulateAutomaton, Transition, EMPTY_STRING};
+use crate::automata::{Automaton, Configuration, SimulateAutomaton, Transition};
 
+use std::collections::BTreeSet;
+
+mod determinize;
+pub mod equivalence;
 pub mod finite_automaton_configuration;
 pub mod finite_automaton_transition;
+pub mod minimize;
 
+pub use equivalence::Equivalence;
 pub use finite_automaton_configuration::FiniteAutomatonConfiguration;
 pub use finite_automaton_transition::FiniteAutomatonTransition;
+pub use minimize::StatePartition;
 
 /// A finite automaton defined by a state graph
 #[derive(Default)]
@@ -12,6 +19,87 @@ pub struct FiniteAutomaton {
     pub automaton: Automaton<FiniteAutomatonTransition>,
 }
 
+impl FiniteAutomaton {
+    /// Returns the set of symbols read by any transition, excluding the empty string
+    pub fn alphabet(&self) -> BTreeSet<char> {
+        self.automaton
+            .transitions()
+            .into_iter()
+            .flat_map(|transition| transition.read().chars())
+            .collect()
+    }
+
+    /// Returns the given states along with every state reachable from them
+    /// using only empty string transitions
+    pub fn epsilon_closure(&self, states: &BTreeSet<u32>) -> BTreeSet<u32> {
+        let mut closure = states.clone();
+        let mut stack: Vec<u32> = states.iter().cloned().collect();
+        while let Some(state) = stack.pop() {
+            for transition in self.automaton.transitions_from(state) {
+                if transition.read().is_empty() && closure.insert(transition.to()) {
+                    stack.push(transition.to());
+                }
+            }
+        }
+        closure
+    }
+
+    /// Returns every state reachable from any of the given states by reading a symbol,
+    /// without taking the closure of the result.
+    /// Transitions reading more than one symbol are not followed.
+    pub fn states_after_symbol(&self, states: &BTreeSet<u32>, symbol: char) -> BTreeSet<u32> {
+        states
+            .iter()
+            .flat_map(|&state| self.automaton.transitions_from(state))
+            .filter(|transition| transition.symbol() == Some(symbol))
+            .map(|transition| transition.to())
+            .collect()
+    }
+
+    /// Returns whether every transition reads at most one symbol
+    pub fn reads_single_symbols(&self) -> bool {
+        self.automaton
+            .transitions()
+            .into_iter()
+            .all(|transition| transition.read().chars().nth(1).is_none())
+    }
+
+    /// Builds an equivalent automaton whose transitions each read at most one symbol,
+    /// replacing each transition reading a longer string with a chain of new states.
+    /// The existing states keep their IDs.
+    pub fn split_transitions(&self) -> FiniteAutomaton {
+        let mut fa = FiniteAutomaton::default();
+        for &state in self.automaton.states_iter() {
+            fa.automaton.try_add_state_with_id(state);
+            fa.automaton
+                .set_final(state, self.automaton.is_final(state));
+        }
+        if let Some(initial) = self.automaton.initial() {
+            fa.automaton.set_initial(initial);
+        }
+
+        for transition in self.automaton.transitions() {
+            let symbols: Vec<char> = transition.read().chars().collect();
+            if symbols.len() <= 1 {
+                fa.automaton.add_transition(transition.clone());
+                continue;
+            }
+            let mut current = transition.from();
+            for (i, &symbol) in symbols.iter().enumerate() {
+                let next = if i == symbols.len() - 1 {
+                    transition.to()
+                } else {
+                    fa.automaton.add_new_state()
+                };
+                fa.automaton
+                    .add_transition(FiniteAutomatonTransition::new(current, next, symbol));
+                current = next;
+            }
+        }
+        fa
+    }
+}
+
 impl SimulateAutomaton for FiniteAutomaton {
     type ConfigurationType = FiniteAutomatonConfiguration;
 
@@ -26,49 +114,39 @@ impl SimulateAutomaton for FiniteAutomaton {
         }
     }
 
-    fn step(&self, mut configuration: Self::ConfigurationType) -> Vec<Self::ConfigurationType> {
-        let (symbol, remaining) = if let Some((symbol, remaining)) = configuration.next_symbol() {
-            (symbol, remaining)
-        } else {
-            let mut new_configurations = Vec::new();
-            for transition in self.automaton.transitions_from(configuration.state()) {
-                let transition_symbol = transition.symbol();
-                if transition_symbol == EMPTY_STRING {
-                    new_configurations.push(FiniteAutomatonConfiguration::new(
-                        transition.to(),
-                        configuration.remaining_string.clone(),
-                    ));
-                }
-            }
-            return new_configurations;
-        };
-
-        let mut new_configurations = Vec::new();
-        for transition in self.automaton.transitions_from(configuration.state()) {
-            let transition_symbol = transition.symbol();
-            if transition_symbol == EMPTY_STRING {
-                new_configurations.push(FiniteAutomatonConfiguration::new(
-                    transition.to(),
-                    configuration.remaining_string.clone(),
-                ));
-            } else if transition_symbol == symbol {
-                new_configurations.push(FiniteAutomatonConfiguration::new(
-                    transition.to(),
-                    remaining.clone(),
-                ));
-            }
-        }
-        new_configurations
+    // Each transition whose string begins the remaining input consumes it,
+    // including ε-transitions, which consume nothing
+    fn step(&self, configuration: Self::ConfigurationType) -> Vec<Self::ConfigurationType> {
+        self.automaton
+            .transitions_from(configuration.state())
+            .into_iter()
+            .filter_map(|transition| {
+                configuration
+                    .remaining_string
+                    .strip_prefix(transition.read())
+                    .map(|remaining| {
+                        FiniteAutomatonConfiguration::new(transition.to(), remaining.to_string())
+                    })
+            })
+            .collect()
     }
 
     fn step_all(
         &self,
         configurations: Vec<Self::ConfigurationType>,
     ) -> Vec<Self::ConfigurationType> {
-        configurations
+        // Identical configurations behave identically, so only one of each is kept,
+        // which stops cycles of empty string transitions from multiplying configurations
+        let mut new_configurations: Vec<Self::ConfigurationType> = Vec::new();
+        for configuration in configurations
             .into_iter()
             .flat_map(|configuration| self.step(configuration))
-            .collect()
+        {
+            if !new_configurations.contains(&configuration) {
+                new_configurations.push(configuration);
+            }
+        }
+        new_configurations
     }
 
     fn check_input(&self, input: &str) -> bool {
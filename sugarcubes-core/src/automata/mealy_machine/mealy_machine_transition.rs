@@ -0,0 +1,39 @@
+use crate::automata::Transition;
+
+/// A transition in a Mealy machine, which produces its output as it reads its symbol
+#[derive(Default, PartialEq, Clone)]
+pub struct MealyMachineTransition {
+    from: u32,
+    to: u32,
+    symbol: char,
+    output: String,
+}
+
+impl Transition for MealyMachineTransition {
+    fn from(&self) -> u32 {
+        self.from
+    }
+
+    fn to(&self) -> u32 {
+        self.to
+    }
+}
+
+impl MealyMachineTransition {
+    pub fn new(from: u32, to: u32, symbol: char, output: &str) -> Self {
+        Self {
+            from,
+            to,
+            symbol,
+            output: output.to_string(),
+        }
+    }
+
+    pub fn symbol(&self) -> char {
+        self.symbol
+    }
+
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+}
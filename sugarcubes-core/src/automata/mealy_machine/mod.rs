@@ -0,0 +1,94 @@
+use crate::automata::{Automaton, Configuration, SimulateAutomaton, Transition};
+
+pub mod mealy_machine_configuration;
+pub mod mealy_machine_transition;
+
+pub use mealy_machine_configuration::MealyMachineConfiguration;
+pub use mealy_machine_transition::MealyMachineTransition;
+
+/// A Mealy machine defined by a state graph, whose transitions each produce an output.
+/// Every transition reads exactly one symbol.
+#[derive(Default)]
+pub struct MealyMachine {
+    pub automaton: Automaton<MealyMachineTransition>,
+}
+
+impl MealyMachine {
+    /// Reads the entire input, returning the produced output and the final configuration,
+    /// or None if the machine gets stuck on every branch before reading the entire input
+    pub fn run(&self, input: &str) -> Option<(String, MealyMachineConfiguration)> {
+        // Every step reads a symbol, so this takes at most one step per symbol
+        let mut configurations = self.initial_configurations(input);
+        loop {
+            if let Some(configuration) = configurations
+                .iter()
+                .find(|configuration| configuration.remaining_string.is_empty())
+            {
+                return Some((configuration.output.clone(), configuration.clone()));
+            }
+
+            if configurations.is_empty() {
+                return None;
+            }
+
+            configurations = self.step_all(configurations);
+        }
+    }
+}
+
+impl SimulateAutomaton for MealyMachine {
+    type ConfigurationType = MealyMachineConfiguration;
+
+    fn initial_configurations(&self, input: &str) -> Vec<Self::ConfigurationType> {
+        if let Some(initial) = self.automaton.initial() {
+            vec![MealyMachineConfiguration::new(
+                initial,
+                input.to_string(),
+                String::new(),
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn step(&self, configuration: Self::ConfigurationType) -> Vec<Self::ConfigurationType> {
+        let (symbol, remaining) = match configuration.next_symbol() {
+            Some(next) => next,
+            None => return Vec::new(),
+        };
+
+        self.automaton
+            .transitions_from(configuration.state())
+            .into_iter()
+            .filter(|transition| transition.symbol() == symbol)
+            .map(|transition| {
+                MealyMachineConfiguration::new(
+                    transition.to(),
+                    remaining.clone(),
+                    configuration.output.clone() + transition.output(),
+                )
+            })
+            .collect()
+    }
+
+    fn step_all(
+        &self,
+        configurations: Vec<Self::ConfigurationType>,
+    ) -> Vec<Self::ConfigurationType> {
+        let mut new_configurations: Vec<Self::ConfigurationType> = Vec::new();
+        for configuration in configurations
+            .into_iter()
+            .flat_map(|configuration| self.step(configuration))
+        {
+            if !new_configurations.contains(&configuration) {
+                new_configurations.push(configuration);
+            }
+        }
+        new_configurations
+    }
+
+    /// A Mealy machine accepts any input it can read entirely
+    fn check_input(&self, input: &str) -> bool {
+        self.run(input).is_some()
+    }
+}
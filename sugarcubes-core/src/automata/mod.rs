@@ -1,9 +1,13 @@
 pub mod automaton;
 pub mod finite_automaton;
+pub mod mealy_machine;
+pub mod moore_machine;
+pub mod pushdown_automaton;
 pub mod simulate_automaton;
 pub mod state;
 pub mod transition;
 pub mod transition_set;
+pub mod turing_machine;
 
 pub const EMPTY_STRING: char = 'ε';
 
@@ -0,0 +1,112 @@
+use crate::automata::{
+    finite_automaton::FiniteAutomatonTransition, Automaton, Configuration, SimulateAutomaton,
+    Transition,
+};
+
+use std::collections::BTreeMap;
+
+pub mod moore_machine_configuration;
+
+pub use moore_machine_configuration::MooreMachineConfiguration;
+
+/// A Moore machine defined by a state graph, whose states each produce an output upon being entered.
+/// Every transition reads exactly one symbol, just like in a finite automaton.
+#[derive(Default)]
+pub struct MooreMachine {
+    pub automaton: Automaton<FiniteAutomatonTransition>,
+    outputs: BTreeMap<u32, String>,
+}
+
+impl MooreMachine {
+    /// Returns the output of a state, which is empty unless it has been set
+    pub fn output(&self, state: u32) -> &str {
+        self.outputs.get(&state).map_or("", String::as_str)
+    }
+
+    pub fn set_output(&mut self, state: u32, output: &str) {
+        if output.is_empty() {
+            self.outputs.remove(&state);
+        } else {
+            self.outputs.insert(state, output.to_string());
+        }
+    }
+
+    /// Reads the entire input, returning the produced output and the final configuration,
+    /// or None if the machine gets stuck on every branch before reading the entire input
+    pub fn run(&self, input: &str) -> Option<(String, MooreMachineConfiguration)> {
+        // Every step reads a symbol, so this takes at most one step per symbol
+        let mut configurations = self.initial_configurations(input);
+        loop {
+            if let Some(configuration) = configurations
+                .iter()
+                .find(|configuration| configuration.remaining_string.is_empty())
+            {
+                return Some((configuration.output.clone(), configuration.clone()));
+            }
+
+            if configurations.is_empty() {
+                return None;
+            }
+
+            configurations = self.step_all(configurations);
+        }
+    }
+}
+
+impl SimulateAutomaton for MooreMachine {
+    type ConfigurationType = MooreMachineConfiguration;
+
+    /// The initial state's output is produced before reading any input
+    fn initial_configurations(&self, input: &str) -> Vec<Self::ConfigurationType> {
+        if let Some(initial) = self.automaton.initial() {
+            vec![MooreMachineConfiguration::new(
+                initial,
+                input.to_string(),
+                self.output(initial).to_string(),
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn step(&self, configuration: Self::ConfigurationType) -> Vec<Self::ConfigurationType> {
+        let (symbol, remaining) = match configuration.next_symbol() {
+            Some(next) => next,
+            None => return Vec::new(),
+        };
+
+        self.automaton
+            .transitions_from(configuration.state())
+            .into_iter()
+            .filter(|transition| transition.symbol() == Some(symbol))
+            .map(|transition| {
+                MooreMachineConfiguration::new(
+                    transition.to(),
+                    remaining.clone(),
+                    configuration.output.clone() + self.output(transition.to()),
+                )
+            })
+            .collect()
+    }
+
+    fn step_all(
+        &self,
+        configurations: Vec<Self::ConfigurationType>,
+    ) -> Vec<Self::ConfigurationType> {
+        let mut new_configurations: Vec<Self::ConfigurationType> = Vec::new();
+        for configuration in configurations
+            .into_iter()
+            .flat_map(|configuration| self.step(configuration))
+        {
+            if !new_configurations.contains(&configuration) {
+                new_configurations.push(configuration);
+            }
+        }
+        new_configurations
+    }
+
+    /// A Moore machine accepts any input it can read entirely
+    fn check_input(&self, input: &str) -> bool {
+        self.run(input).is_some()
+    }
+}
@@ -0,0 +1,31 @@
+use crate::automata::Configuration;
+
+/// A configuration in a Moore machine, including the remaining input and the output so far
+#[derive(Clone, Debug, PartialEq)]
+pub struct MooreMachineConfiguration {
+    state: u32,
+    pub remaining_string: String,
+    pub output: String,
+}
+
+impl Configuration for MooreMachineConfiguration {
+    fn state(&self) -> u32 {
+        self.state
+    }
+}
+
+impl MooreMachineConfiguration {
+    pub fn new(state: u32, remaining_string: String, output: String) -> Self {
+        Self {
+            state,
+            remaining_string,
+            output,
+        }
+    }
+
+    /// Returns the next symbol and the string remaining after it, or None if there is no next symbol
+    pub fn next_symbol(&self) -> Option<(char, String)> {
+        let mut chars = self.remaining_string.chars();
+        chars.next().map(|next| (next, chars.collect()))
+    }
+}
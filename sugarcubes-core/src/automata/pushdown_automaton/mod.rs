@@ -0,0 +1,188 @@
+use crate::automata::{Automaton, Configuration, SimulateAutomaton, Transition, EMPTY_STRING};
+
+use std::collections::BTreeSet;
+
+mod normalize;
+pub mod pushdown_automaton_configuration;
+pub mod pushdown_automaton_transition;
+mod to_grammar;
+
+pub use pushdown_automaton_configuration::PushdownAutomatonConfiguration;
+pub use pushdown_automaton_transition::PushdownAutomatonTransition;
+
+/// The symbol on the stack when a simulation starts, as in JFLAP
+pub const DEFAULT_INITIAL_STACK_SYMBOL: char = 'Z';
+
+/// The default bound on how many configurations are simulated at once
+pub const DEFAULT_MAX_CONFIGURATIONS: usize = 1000;
+
+/// The condition under which a pushdown automaton accepts its input
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Acceptance {
+    /// The input is consumed in a final state, whatever is on the stack
+    FinalState,
+    /// The input is consumed with an empty stack, in any state
+    EmptyStack,
+}
+
+/// The outcome of running a pushdown automaton on an input
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PushdownAutomatonResult {
+    /// Some configuration accepted the input
+    Accepted,
+    /// Every configuration stopped without accepting
+    Rejected,
+    /// No configuration accepted, but the search was cut short, either by dropping configurations
+    /// beyond the automaton's limit or by running out of steps, so the input may be accepted
+    Truncated,
+}
+
+/// A pushdown automaton defined by a state graph
+pub struct PushdownAutomaton {
+    pub automaton: Automaton<PushdownAutomatonTransition>,
+    pub acceptance: Acceptance,
+    pub initial_stack_symbol: char,
+    // Nondeterminism can create unboundedly many configurations (e.g. by pushing on empty transitions),
+    // so only this many are kept after each step
+    pub max_configurations: usize,
+}
+
+impl Default for PushdownAutomaton {
+    fn default() -> Self {
+        Self {
+            automaton: Automaton::default(),
+            acceptance: Acceptance::FinalState,
+            initial_stack_symbol: DEFAULT_INITIAL_STACK_SYMBOL,
+            max_configurations: DEFAULT_MAX_CONFIGURATIONS,
+        }
+    }
+}
+
+impl PushdownAutomaton {
+    /// Returns whether a configuration accepts, according to the automaton's acceptance condition
+    pub fn is_accepting(&self, configuration: &PushdownAutomatonConfiguration) -> bool {
+        configuration.remaining_string.is_empty()
+            && match self.acceptance {
+                Acceptance::FinalState => self.automaton.is_final(configuration.state()),
+                Acceptance::EmptyStack => configuration.stack.is_empty(),
+            }
+    }
+
+    /// Steps every configuration, keeping at most `max_configurations` of the results,
+    /// and returns whether any were dropped to stay within the limit
+    pub fn step_all_bounded(
+        &self,
+        configurations: Vec<PushdownAutomatonConfiguration>,
+    ) -> (Vec<PushdownAutomatonConfiguration>, bool) {
+        let mut new_configurations: Vec<PushdownAutomatonConfiguration> = Vec::new();
+        for configuration in configurations
+            .into_iter()
+            .flat_map(|configuration| self.step(configuration))
+        {
+            if new_configurations.contains(&configuration) {
+                continue;
+            }
+
+            if new_configurations.len() >= self.max_configurations {
+                return (new_configurations, true);
+            }
+
+            new_configurations.push(configuration);
+        }
+        (new_configurations, false)
+    }
+
+    /// Runs the automaton on an input until some configuration accepts or all of them stop
+    pub fn run(&self, input: &str) -> PushdownAutomatonResult {
+        let mut configurations = self.initial_configurations(input);
+        let mut truncated = false;
+
+        const MAX_ITERS: usize = 1000;
+        for _ in 0..MAX_ITERS {
+            if configurations.is_empty() {
+                return if truncated {
+                    PushdownAutomatonResult::Truncated
+                } else {
+                    PushdownAutomatonResult::Rejected
+                };
+            }
+
+            if configurations
+                .iter()
+                .any(|configuration| self.is_accepting(configuration))
+            {
+                return PushdownAutomatonResult::Accepted;
+            }
+
+            let (new_configurations, dropped) = self.step_all_bounded(configurations);
+            configurations = new_configurations;
+            truncated |= dropped;
+        }
+
+        PushdownAutomatonResult::Truncated
+    }
+}
+
+impl SimulateAutomaton for PushdownAutomaton {
+    type ConfigurationType = PushdownAutomatonConfiguration;
+
+    fn initial_configurations(&self, input: &str) -> Vec<Self::ConfigurationType> {
+        if let Some(initial) = self.automaton.initial() {
+            vec![PushdownAutomatonConfiguration::new(
+                initial,
+                input.to_string(),
+                self.initial_stack_symbol.to_string(),
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn step(&self, configuration: Self::ConfigurationType) -> Vec<Self::ConfigurationType> {
+        let next_symbol = configuration.next_symbol();
+
+        let mut new_configurations = Vec::new();
+        for transition in self.automaton.transitions_from(configuration.state()) {
+            let remaining = if transition.symbol() == EMPTY_STRING {
+                configuration.remaining_string.clone()
+            } else {
+                match &next_symbol {
+                    Some((symbol, remaining)) if *symbol == transition.symbol() => {
+                        remaining.clone()
+                    }
+                    _ => continue,
+                }
+            };
+
+            if let Some(rest_of_stack) = configuration.stack.strip_prefix(transition.pop()) {
+                new_configurations.push(PushdownAutomatonConfiguration::new(
+                    transition.to(),
+                    remaining,
+                    transition.push().to_string() + rest_of_stack,
+                ));
+            }
+        }
+        new_configurations
+    }
+
+    fn step_all(
+        &self,
+        configurations: Vec<Self::ConfigurationType>,
+    ) -> Vec<Self::ConfigurationType> {
+        self.step_all_bounded(configurations).0
+    }
+
+    fn check_input(&self, input: &str) -> bool {
+        self.run(input) == PushdownAutomatonResult::Accepted
+    }
+}
+
+/// Returns a symbol for the stack which is not among the given symbols,
+/// preferring the usual initial stack symbol
+pub(crate) fn unused_stack_symbol(used: &BTreeSet<char>) -> char {
+    std::iter::once(DEFAULT_INITIAL_STACK_SYMBOL)
+        .chain("$#@%&".chars())
+        .chain('\u{100}'..=char::MAX)
+        .find(|symbol| !used.contains(symbol))
+        .unwrap()
+}
@@ -0,0 +1,223 @@
+use super::{unused_stack_symbol, Acceptance, PushdownAutomaton, PushdownAutomatonTransition};
+use crate::automata::{Transition, EMPTY_STRING};
+
+use std::collections::BTreeSet;
+
+impl PushdownAutomaton {
+    /// Returns the symbols which may be on the stack: the initial stack symbol,
+    /// and every symbol a transition pops or pushes
+    pub fn stack_alphabet(&self) -> BTreeSet<char> {
+        self.automaton
+            .transitions()
+            .into_iter()
+            .flat_map(|transition| transition.pop().chars().chain(transition.push().chars()))
+            .chain(std::iter::once(self.initial_stack_symbol))
+            .collect()
+    }
+
+    /// Builds an equivalent pushdown automaton in the form required by the triple construction:
+    /// it has a single final state with no transitions leaving it, which is only entered
+    /// as the stack becomes empty, and each transition pops exactly one symbol
+    /// and either pushes two symbols (a net push of one) or pushes nothing (a net pop of one).
+    ///
+    /// A new initial state puts a fresh bottom marker under the initial stack symbol,
+    /// so the new final state is entered by popping it once the original automaton accepts.
+    /// Transitions popping or pushing other amounts are split into chains through new states,
+    /// using a fresh helper symbol where a single symbol must be pushed.
+    pub fn normalize(&self) -> PushdownAutomaton {
+        let mut normalized = PushdownAutomaton {
+            initial_stack_symbol: self.initial_stack_symbol,
+            max_configurations: self.max_configurations,
+            ..PushdownAutomaton::default()
+        };
+        for &state in self.automaton.states_iter() {
+            normalized.automaton.try_add_state_with_id(state);
+        }
+
+        let mut alphabet = self.stack_alphabet();
+        let bottom = unused_stack_symbol(&alphabet);
+        alphabet.insert(bottom);
+        let helper = unused_stack_symbol(&alphabet);
+        // The symbols which may be on top of the stack, including the bottom marker
+        // when the original automaton's stack is empty
+        let tops = alphabet;
+
+        let start = normalized.automaton.add_new_state();
+        let accept = normalized.automaton.add_new_state();
+        normalized.automaton.set_initial(start);
+        normalized.automaton.set_final(accept, true);
+        let stack_start: String = [self.initial_stack_symbol, bottom].iter().collect();
+        if let Some(initial) = self.automaton.initial() {
+            normalized.add_single_pop(
+                start,
+                initial,
+                EMPTY_STRING,
+                self.initial_stack_symbol,
+                &stack_start,
+                helper,
+            );
+        }
+
+        for transition in self.automaton.transitions() {
+            let mut pop = transition.pop().chars();
+            match pop.next() {
+                // A transition popping nothing applies whatever is on top, so it pops that
+                // symbol and pushes it back
+                None => {
+                    for &top in &tops {
+                        let push = format!("{}{}", transition.push(), top);
+                        normalized.add_single_pop(
+                            transition.from(),
+                            transition.to(),
+                            transition.symbol(),
+                            top,
+                            &push,
+                            helper,
+                        );
+                    }
+                }
+                Some(first) => {
+                    // Pop the remaining symbols one at a time on empty transitions
+                    let mut from = transition.from();
+                    let (mut symbol, mut popped) = (transition.symbol(), first);
+                    for next in pop {
+                        let to = normalized.automaton.add_new_state();
+                        normalized.add_single_pop(from, to, symbol, popped, "", helper);
+                        from = to;
+                        symbol = EMPTY_STRING;
+                        popped = next;
+                    }
+                    normalized.add_single_pop(
+                        from,
+                        transition.to(),
+                        symbol,
+                        popped,
+                        transition.push(),
+                        helper,
+                    );
+                }
+            }
+        }
+
+        let bottom_string = bottom.to_string();
+        match self.acceptance {
+            Acceptance::FinalState => {
+                // Leaving a final state, empty the stack down to the marker, then pop it
+                let drain = normalized.automaton.add_new_state();
+                let finals = self
+                    .automaton
+                    .states_iter()
+                    .filter(|&&state| self.automaton.is_final(state));
+                for &state in finals.chain(std::iter::once(&drain)) {
+                    for &top in tops.iter().filter(|&&top| top != bottom) {
+                        normalized
+                            .automaton
+                            .add_transition(PushdownAutomatonTransition::new(
+                                state,
+                                drain,
+                                EMPTY_STRING,
+                                &top.to_string(),
+                                "",
+                            ));
+                    }
+                    normalized
+                        .automaton
+                        .add_transition(PushdownAutomatonTransition::new(
+                            state,
+                            accept,
+                            EMPTY_STRING,
+                            &bottom_string,
+                            "",
+                        ));
+                }
+            }
+            Acceptance::EmptyStack => {
+                // The original stack is empty exactly when the marker is on top
+                for &state in self.automaton.states_iter() {
+                    normalized
+                        .automaton
+                        .add_transition(PushdownAutomatonTransition::new(
+                            state,
+                            accept,
+                            EMPTY_STRING,
+                            &bottom_string,
+                            "",
+                        ));
+                }
+            }
+        }
+
+        normalized
+    }
+
+    // Add transitions popping exactly one symbol and pushing a string,
+    // split so each pushes two symbols or none
+    fn add_single_pop(
+        &mut self,
+        from: u32,
+        to: u32,
+        symbol: char,
+        pop: char,
+        push: &str,
+        helper: char,
+    ) {
+        let push: Vec<char> = push.chars().collect();
+        match push.len() {
+            0 | 2 => {
+                self.automaton
+                    .add_transition(PushdownAutomatonTransition::new(
+                        from,
+                        to,
+                        symbol,
+                        &pop.to_string(),
+                        &push.iter().collect::<String>(),
+                    ));
+            }
+            // Push the helper over the symbol, then pop the helper
+            1 => {
+                let middle = self.automaton.add_new_state();
+                self.automaton
+                    .add_transition(PushdownAutomatonTransition::new(
+                        from,
+                        middle,
+                        symbol,
+                        &pop.to_string(),
+                        &format!("{}{}", helper, push[0]),
+                    ));
+                self.automaton
+                    .add_transition(PushdownAutomatonTransition::new(
+                        middle,
+                        to,
+                        EMPTY_STRING,
+                        &helper.to_string(),
+                        "",
+                    ));
+            }
+            // Push the two bottom symbols, then repeatedly pop the top symbol
+            // and push it back under the symbol above it
+            length => {
+                let mut from = from;
+                let mut symbol = symbol;
+                let mut popped = pop;
+                for i in (0..length - 1).rev() {
+                    let next = if i == 0 {
+                        to
+                    } else {
+                        self.automaton.add_new_state()
+                    };
+                    self.automaton
+                        .add_transition(PushdownAutomatonTransition::new(
+                            from,
+                            next,
+                            symbol,
+                            &popped.to_string(),
+                            &push[i..i + 2].iter().collect::<String>(),
+                        ));
+                    from = next;
+                    symbol = EMPTY_STRING;
+                    popped = push[i];
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::automata::Configuration;
+
+/// A configuration in a pushdown automaton, including the remaining input and the stack
+#[derive(Clone, PartialEq, Debug)]
+pub struct PushdownAutomatonConfiguration {
+    state: u32,
+    pub remaining_string: String,
+    // The top of the stack is the first character
+    pub stack: String,
+}
+
+impl Configuration for PushdownAutomatonConfiguration {
+    fn state(&self) -> u32 {
+        self.state
+    }
+}
+
+impl PushdownAutomatonConfiguration {
+    pub fn new(state: u32, remaining_string: String, stack: String) -> Self {
+        Self {
+            state,
+            remaining_string,
+            stack,
+        }
+    }
+
+    /// Returns the next symbol and the string remaining after it, or None if there is no next symbol
+    pub fn next_symbol(&self) -> Option<(char, String)> {
+        let mut chars = self.remaining_string.chars();
+        chars.next().map(|next| (next, chars.collect()))
+    }
+}
@@ -0,0 +1,48 @@
+use crate::automata::Transition;
+
+/// A transition between two states in a pushdown automaton,
+/// which reads a symbol (or the empty string), pops a string off the stack,
+/// and pushes a string onto the stack.
+/// Stack strings are written with the top of the stack first.
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct PushdownAutomatonTransition {
+    from: u32,
+    to: u32,
+    symbol: char,
+    pop: String,
+    push: String,
+}
+
+impl Transition for PushdownAutomatonTransition {
+    fn from(&self) -> u32 {
+        self.from
+    }
+
+    fn to(&self) -> u32 {
+        self.to
+    }
+}
+
+impl PushdownAutomatonTransition {
+    pub fn new(from: u32, to: u32, symbol: char, pop: &str, push: &str) -> Self {
+        Self {
+            from,
+            to,
+            symbol,
+            pop: pop.to_string(),
+            push: push.to_string(),
+        }
+    }
+
+    pub fn symbol(&self) -> char {
+        self.symbol
+    }
+
+    pub fn pop(&self) -> &str {
+        &self.pop
+    }
+
+    pub fn push(&self) -> &str {
+        &self.push
+    }
+}
@@ -0,0 +1,245 @@
+use super::PushdownAutomaton;
+use crate::automata::{Transition, EMPTY_STRING};
+use crate::grammar::{is_variable, Grammar, Production, TransformError, DEFAULT_START_SYMBOL};
+
+use std::collections::{btree_map, BTreeMap, BTreeSet, VecDeque};
+
+// A variable of the triple construction, deriving the strings read while going from
+// the first state to the last and popping the symbol, with the stack below it untouched
+type Triple = (u32, char, u32);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum TripleSymbol {
+    Terminal(char),
+    Variable(Triple),
+}
+
+impl PushdownAutomaton {
+    /// Builds a context-free grammar generating the language of the automaton
+    /// using the triple construction, after converting it to the form it requires
+    /// (see [PushdownAutomaton::normalize]).
+    ///
+    /// A transition from p reading a and popping X is a production [p X q] → a
+    /// if it pushes nothing and goes to q, or [p X s] → a [r Y q] [q W s] for every q and s
+    /// if it pushes YW and goes to r. Variables deriving nothing or unreachable from the start
+    /// are removed, and variables with a single production are substituted into the rest,
+    /// before the remaining triples are named by uppercase letters.
+    pub fn to_grammar(&self) -> Result<Grammar, TransformError> {
+        let alphabet: BTreeSet<char> = self
+            .automaton
+            .transitions()
+            .into_iter()
+            .map(|transition| transition.symbol())
+            .filter(|&symbol| symbol != EMPTY_STRING)
+            .collect();
+        if let Some(&symbol) = alphabet.iter().find(|&&symbol| is_variable(symbol)) {
+            return Err(TransformError::VariableAsTerminal(symbol));
+        }
+
+        let pda = self.normalize();
+        let (initial, accept) = match (
+            pda.automaton.initial(),
+            pda.automaton
+                .states_iter()
+                .find(|&&state| pda.automaton.is_final(state)),
+        ) {
+            (Some(initial), Some(&accept)) => (initial, accept),
+            _ => return Ok(Grammar::default()),
+        };
+        let start: Triple = (initial, pda.initial_stack_symbol, accept);
+        let states: Vec<u32> = pda.automaton.states_iter().copied().collect();
+
+        // Build the productions of each triple reachable from the start
+        let mut productions: BTreeMap<Triple, BTreeSet<Vec<TripleSymbol>>> = BTreeMap::new();
+        let mut queue: VecDeque<Triple> = std::iter::once(start).collect();
+        productions.insert(start, BTreeSet::new());
+        while let Some(triple) = queue.pop_front() {
+            let (from, popped, last) = triple;
+            let mut rights = BTreeSet::new();
+            for transition in pda.automaton.transitions_from(from) {
+                if transition.pop() != popped.to_string() {
+                    continue;
+                }
+                let read: Vec<TripleSymbol> = Some(transition.symbol())
+                    .filter(|&symbol| symbol != EMPTY_STRING)
+                    .map(TripleSymbol::Terminal)
+                    .into_iter()
+                    .collect();
+                let pushed: Vec<char> = transition.push().chars().collect();
+                match pushed[..] {
+                    [] if transition.to() == last => {
+                        rights.insert(read);
+                    }
+                    [top, below] => {
+                        for &middle in &states {
+                            let mut right = read.clone();
+                            right.push(TripleSymbol::Variable((transition.to(), top, middle)));
+                            right.push(TripleSymbol::Variable((middle, below, last)));
+                            rights.insert(right);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            for right in &rights {
+                for symbol in right {
+                    if let TripleSymbol::Variable(variable) = *symbol {
+                        if let btree_map::Entry::Vacant(entry) = productions.entry(variable) {
+                            entry.insert(BTreeSet::new());
+                            queue.push_back(variable);
+                        }
+                    }
+                }
+            }
+            productions.insert(triple, rights);
+        }
+
+        remove_useless_triples(&mut productions, start);
+        substitute_single_productions(&mut productions, start);
+        name_triples(&productions, start)
+    }
+}
+
+// Remove triples deriving no string of terminals, then those unreachable from the start
+fn remove_useless_triples(
+    productions: &mut BTreeMap<Triple, BTreeSet<Vec<TripleSymbol>>>,
+    start: Triple,
+) {
+    let mut generating = BTreeSet::new();
+    loop {
+        let mut changed = false;
+        for (&triple, rights) in productions.iter() {
+            if !generating.contains(&triple)
+                && rights.iter().any(|right| {
+                    right.iter().all(|symbol| match symbol {
+                        TripleSymbol::Terminal(_) => true,
+                        TripleSymbol::Variable(variable) => generating.contains(variable),
+                    })
+                })
+            {
+                generating.insert(triple);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    productions.retain(|triple, _| generating.contains(triple));
+    for rights in productions.values_mut() {
+        rights.retain(|right| {
+            right.iter().all(|symbol| match symbol {
+                TripleSymbol::Terminal(_) => true,
+                TripleSymbol::Variable(variable) => generating.contains(variable),
+            })
+        });
+    }
+
+    let mut reachable = BTreeSet::new();
+    let mut stack = Vec::new();
+    if productions.contains_key(&start) {
+        reachable.insert(start);
+        stack.push(start);
+    }
+    while let Some(triple) = stack.pop() {
+        for right in &productions[&triple] {
+            for symbol in right {
+                if let TripleSymbol::Variable(variable) = *symbol {
+                    if reachable.insert(variable) {
+                        stack.push(variable);
+                    }
+                }
+            }
+        }
+    }
+    productions.retain(|triple, _| reachable.contains(triple));
+}
+
+// Replace each triple other than the start which has a single, non-recursive production
+// with its right side, so the grammar needs fewer variables
+fn substitute_single_productions(
+    productions: &mut BTreeMap<Triple, BTreeSet<Vec<TripleSymbol>>>,
+    start: Triple,
+) {
+    loop {
+        let single = productions.iter().find_map(|(&triple, rights)| {
+            let right = rights.iter().next().filter(|_| rights.len() == 1)?;
+            if triple == start || right.contains(&TripleSymbol::Variable(triple)) {
+                None
+            } else {
+                Some((triple, right.clone()))
+            }
+        });
+        let (triple, replacement) = match single {
+            Some(single) => single,
+            None => return,
+        };
+
+        productions.remove(&triple);
+        for rights in productions.values_mut() {
+            *rights = rights
+                .iter()
+                .map(|right| {
+                    right
+                        .iter()
+                        .flat_map(|&symbol| {
+                            if symbol == TripleSymbol::Variable(triple) {
+                                replacement.clone()
+                            } else {
+                                vec![symbol]
+                            }
+                        })
+                        .collect()
+                })
+                .collect();
+        }
+    }
+}
+
+// Name the start triple S and the others by uppercase letters in the order they are reached,
+// listing the productions of each variable together
+fn name_triples(
+    productions: &BTreeMap<Triple, BTreeSet<Vec<TripleSymbol>>>,
+    start: Triple,
+) -> Result<Grammar, TransformError> {
+    let mut grammar = Grammar::default();
+    if !productions.contains_key(&start) {
+        return Ok(grammar);
+    }
+
+    let mut letters = ('A'..='Z').filter(|&letter| letter != DEFAULT_START_SYMBOL);
+    let mut names: BTreeMap<Triple, char> =
+        std::iter::once((start, DEFAULT_START_SYMBOL)).collect();
+    let mut order = vec![start];
+    let mut i = 0;
+    while i < order.len() {
+        for right in &productions[&order[i]] {
+            for symbol in right {
+                if let TripleSymbol::Variable(variable) = *symbol {
+                    if let btree_map::Entry::Vacant(entry) = names.entry(variable) {
+                        entry.insert(letters.next().ok_or(TransformError::OutOfVariables)?);
+                        order.push(variable);
+                    }
+                }
+            }
+        }
+        i += 1;
+    }
+
+    for triple in order {
+        let lhs = names[&triple].to_string();
+        for right in &productions[&triple] {
+            let rhs: String = right
+                .iter()
+                .map(|symbol| match symbol {
+                    TripleSymbol::Terminal(terminal) => *terminal,
+                    TripleSymbol::Variable(variable) => names[variable],
+                })
+                .collect();
+            grammar.add_production(Production::new(&lhs, &rhs));
+        }
+    }
+    Ok(grammar)
+}
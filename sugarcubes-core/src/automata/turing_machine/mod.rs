@@ -0,0 +1,200 @@
+use crate::automata::{Automaton, Configuration, SimulateAutomaton, Transition};
+
+use std::collections::BTreeSet;
+
+pub mod tape;
+pub mod turing_machine_configuration;
+pub mod turing_machine_transition;
+
+pub use tape::{Direction, Tape};
+pub use turing_machine_configuration::TuringMachineConfiguration;
+pub use turing_machine_transition::{TapeAction, TuringMachineTransition};
+
+/// The symbol filling every unwritten cell of the tape
+pub const BLANK_SYMBOL: char = '□';
+
+/// The default number of steps simulated before giving up on a machine halting
+pub const DEFAULT_MAX_STEPS: usize = 1000;
+
+/// The condition under which a Turing machine accepts its input
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Acceptance {
+    /// The machine accepts as soon as it enters a final state
+    FinalState,
+    /// The machine accepts whenever it halts outside of a reject state
+    Halting,
+}
+
+/// The outcome of running a Turing machine on an input
+#[derive(Clone, Debug, PartialEq)]
+pub enum TuringMachineResult {
+    /// Some branch of the computation accepted, ending in the given configuration
+    Accepted(TuringMachineConfiguration),
+    /// No branch accepted, and some branch entered a reject state,
+    /// ending in the given configuration
+    Rejected(TuringMachineConfiguration),
+    /// Every branch of the computation halted without accepting or rejecting
+    HaltedWithoutAccepting,
+    /// Some branch was still running after the given number of steps
+    DidNotHalt(usize),
+}
+
+/// A Turing machine with one or more tapes, defined by a state graph.
+/// The input is written on the first tape, and the others start blank.
+pub struct TuringMachine {
+    pub automaton: Automaton<TuringMachineTransition>,
+    // Transitions with a different number of tape actions are never taken
+    pub tape_count: usize,
+    pub acceptance: Acceptance,
+    pub blank_symbol: char,
+    pub max_steps: usize,
+    reject_states: BTreeSet<u32>,
+}
+
+impl Default for TuringMachine {
+    fn default() -> Self {
+        Self {
+            automaton: Automaton::default(),
+            tape_count: 1,
+            acceptance: Acceptance::FinalState,
+            blank_symbol: BLANK_SYMBOL,
+            max_steps: DEFAULT_MAX_STEPS,
+            reject_states: BTreeSet::new(),
+        }
+    }
+}
+
+impl TuringMachine {
+    /// Creates a machine with no states and the given number of tapes
+    pub fn with_tapes(tape_count: usize) -> Self {
+        Self {
+            tape_count,
+            ..Self::default()
+        }
+    }
+
+    pub fn is_reject(&self, state: u32) -> bool {
+        self.reject_states.contains(&state)
+    }
+
+    pub fn set_reject(&mut self, state: u32, value: bool) {
+        if value {
+            self.reject_states.insert(state);
+        } else {
+            self.reject_states.remove(&state);
+        }
+    }
+
+    /// Returns whether a configuration is in a state that stops the machine
+    /// regardless of its transitions
+    pub fn is_stopped(&self, configuration: &TuringMachineConfiguration) -> bool {
+        self.is_reject(configuration.state())
+            || (self.acceptance == Acceptance::FinalState
+                && self.automaton.is_final(configuration.state()))
+    }
+
+    /// Runs the machine until it accepts, every branch halts, or `max_steps` steps have passed
+    pub fn run(&self, input: &str) -> TuringMachineResult {
+        let mut configurations = self.initial_configurations(input);
+        let mut rejected = None;
+
+        for _ in 0..=self.max_steps {
+            let mut running = Vec::new();
+            for configuration in configurations {
+                if self.is_reject(configuration.state()) {
+                    rejected.get_or_insert(configuration);
+                    continue;
+                }
+
+                let next = self.step(configuration.clone());
+                let accepted = match self.acceptance {
+                    Acceptance::FinalState => self.automaton.is_final(configuration.state()),
+                    Acceptance::Halting => next.is_empty(),
+                };
+                if accepted {
+                    return TuringMachineResult::Accepted(configuration);
+                }
+
+                for configuration in next {
+                    if !running.contains(&configuration) {
+                        running.push(configuration);
+                    }
+                }
+            }
+
+            if running.is_empty() {
+                return match rejected {
+                    Some(configuration) => TuringMachineResult::Rejected(configuration),
+                    None => TuringMachineResult::HaltedWithoutAccepting,
+                };
+            }
+
+            configurations = running;
+        }
+
+        TuringMachineResult::DidNotHalt(self.max_steps)
+    }
+}
+
+impl SimulateAutomaton for TuringMachine {
+    type ConfigurationType = TuringMachineConfiguration;
+
+    fn initial_configurations(&self, input: &str) -> Vec<Self::ConfigurationType> {
+        if let Some(initial) = self.automaton.initial() {
+            let mut tapes = vec![Tape::new(input, self.blank_symbol)];
+            for _ in 1..self.tape_count {
+                tapes.push(Tape::new("", self.blank_symbol));
+            }
+            vec![TuringMachineConfiguration::new(initial, tapes)]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn step(&self, configuration: Self::ConfigurationType) -> Vec<Self::ConfigurationType> {
+        if self.is_stopped(&configuration) {
+            return Vec::new();
+        }
+
+        self.automaton
+            .transitions_from(configuration.state())
+            .into_iter()
+            .filter(|transition| {
+                transition.actions().len() == configuration.tapes.len()
+                    && transition
+                        .actions()
+                        .iter()
+                        .zip(&configuration.tapes)
+                        .all(|(action, tape)| action.read == tape.read())
+            })
+            .map(|transition| {
+                let mut tapes = configuration.tapes.clone();
+                for (action, tape) in transition.actions().iter().zip(&mut tapes) {
+                    tape.write(action.write);
+                    tape.move_head(action.direction);
+                }
+                TuringMachineConfiguration::new(transition.to(), tapes)
+            })
+            .collect()
+    }
+
+    fn step_all(
+        &self,
+        configurations: Vec<Self::ConfigurationType>,
+    ) -> Vec<Self::ConfigurationType> {
+        let mut new_configurations: Vec<Self::ConfigurationType> = Vec::new();
+        for configuration in configurations
+            .into_iter()
+            .flat_map(|configuration| self.step(configuration))
+        {
+            if !new_configurations.contains(&configuration) {
+                new_configurations.push(configuration);
+            }
+        }
+        new_configurations
+    }
+
+    fn check_input(&self, input: &str) -> bool {
+        matches!(self.run(input), TuringMachineResult::Accepted(_))
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+/// The direction a tape head moves after writing
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    #[default]
+    Stay,
+}
+
+impl Direction {
+    /// Returns the JFLAP letter for the direction
+    pub fn to_char(self) -> char {
+        match self {
+            Self::Left => 'L',
+            Self::Right => 'R',
+            Self::Stay => 'S',
+        }
+    }
+
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Self::Left),
+            'R' => Some(Self::Right),
+            'S' => Some(Self::Stay),
+            _ => None,
+        }
+    }
+}
+
+/// A tape which is blank everywhere except for finitely many cells,
+/// and grows in either direction as the head moves
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tape {
+    cells: VecDeque<char>,
+    head: usize,
+    blank: char,
+}
+
+impl Tape {
+    /// Creates a tape holding a string, with the head on its first symbol
+    pub fn new(contents: &str, blank: char) -> Self {
+        let mut cells: VecDeque<char> = contents.chars().collect();
+        if cells.is_empty() {
+            cells.push_back(blank);
+        }
+        Self {
+            cells,
+            head: 0,
+            blank,
+        }
+    }
+
+    pub fn read(&self) -> char {
+        self.cells[self.head]
+    }
+
+    pub fn write(&mut self, symbol: char) {
+        self.cells[self.head] = symbol;
+    }
+
+    pub fn move_head(&mut self, direction: Direction) {
+        match direction {
+            Direction::Left => {
+                if self.head == 0 {
+                    self.cells.push_front(self.blank);
+                } else {
+                    self.head -= 1;
+                }
+            }
+            Direction::Right => {
+                self.head += 1;
+                if self.head == self.cells.len() {
+                    self.cells.push_back(self.blank);
+                }
+            }
+            Direction::Stay => {}
+        }
+    }
+
+    /// Returns the index of the head within `cells()`
+    pub fn head(&self) -> usize {
+        self.head
+    }
+
+    /// Returns every cell that has been visited or written, from left to right
+    pub fn cells(&self) -> &VecDeque<char> {
+        &self.cells
+    }
+
+    /// Returns the tape contents without surrounding blanks
+    pub fn contents(&self) -> String {
+        let contents: String = self.cells.iter().collect();
+        contents.trim_matches(self.blank).to_string()
+    }
+}
+
+impl fmt::Display for Tape {
+    /// Writes the visited cells, with the symbol under the head in brackets
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, cell) in self.cells.iter().enumerate() {
+            if i == self.head {
+                write!(f, "[{}]", cell)?;
+            } else {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use super::Tape;
+use crate::automata::Configuration;
+
+/// A configuration in a Turing machine, including every tape
+#[derive(Clone, PartialEq, Debug)]
+pub struct TuringMachineConfiguration {
+    state: u32,
+    pub tapes: Vec<Tape>,
+}
+
+impl Configuration for TuringMachineConfiguration {
+    fn state(&self) -> u32 {
+        self.state
+    }
+}
+
+impl TuringMachineConfiguration {
+    pub fn new(state: u32, tapes: Vec<Tape>) -> Self {
+        Self { state, tapes }
+    }
+}
@@ -0,0 +1,57 @@
+use super::Direction;
+use crate::automata::Transition;
+
+/// What a transition does to a single tape:
+/// the symbol it reads, the symbol it writes in its place, and where the head moves
+#[derive(Default, PartialEq, Clone, Copy, Debug)]
+pub struct TapeAction {
+    pub read: char,
+    pub write: char,
+    pub direction: Direction,
+}
+
+impl TapeAction {
+    pub fn new(read: char, write: char, direction: Direction) -> Self {
+        Self {
+            read,
+            write,
+            direction,
+        }
+    }
+}
+
+/// A transition between two states in a Turing machine,
+/// which acts on every tape at once
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct TuringMachineTransition {
+    from: u32,
+    to: u32,
+    // One action for each tape, in order
+    actions: Vec<TapeAction>,
+}
+
+impl Transition for TuringMachineTransition {
+    fn from(&self) -> u32 {
+        self.from
+    }
+
+    fn to(&self) -> u32 {
+        self.to
+    }
+}
+
+impl TuringMachineTransition {
+    /// Creates a transition for a single-tape machine
+    pub fn new(from: u32, to: u32, read: char, write: char, direction: Direction) -> Self {
+        Self::with_actions(from, to, vec![TapeAction::new(read, write, direction)])
+    }
+
+    /// Creates a transition with one action for each tape
+    pub fn with_actions(from: u32, to: u32, actions: Vec<TapeAction>) -> Self {
+        Self { from, to, actions }
+    }
+
+    pub fn actions(&self) -> &[TapeAction] {
+        &self.actions
+    }
+}
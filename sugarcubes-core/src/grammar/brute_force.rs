@@ -0,0 +1,150 @@
+use super::{is_variable, Grammar};
+
+use std::collections::{HashSet, VecDeque};
+
+/// The symbol between consecutive sentential forms of a derivation
+pub const DERIVES_SYMBOL: char = '⇒';
+
+/// Bounds on a brute-force derivation search, as it may otherwise never end
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DerivationLimits {
+    /// The most productions applied in a derivation
+    pub max_depth: usize,
+    /// The most distinct sentential forms visited
+    pub max_nodes: usize,
+}
+
+impl Default for DerivationLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 30,
+            max_nodes: 100_000,
+        }
+    }
+}
+
+/// The outcome of a brute-force derivation search
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DerivationResult {
+    /// The sentential forms of a shortest derivation, from the start symbol to the string
+    Derived(Vec<String>),
+    /// Every derivation was searched without finding the string
+    NotDerivable,
+    /// The search stopped at a limit without finding the string
+    LimitReached,
+}
+
+impl Grammar {
+    /// Searches breadth-first for a derivation of the string, applying every production
+    /// at every position of each sentential form, as JFLAP's brute-force parser does.
+    /// Sentential forms which can no longer derive the string are discarded where the
+    /// grammar allows telling: forms longer than the string if no production shrinks them,
+    /// and for context-free grammars, forms whose terminals cannot be part of the string.
+    pub fn brute_force_derivation(
+        &self,
+        input: &str,
+        limits: DerivationLimits,
+    ) -> DerivationResult {
+        // The start symbol may derive the empty string if it appears on no right side
+        let start_on_right = self
+            .productions
+            .iter()
+            .any(|production| production.rhs.contains(self.start_symbol));
+        let non_contracting = self.productions.iter().all(|production| {
+            production.rhs.chars().count() >= production.lhs.chars().count()
+                || (production.single_variable_lhs() == Some(self.start_symbol) && !start_on_right)
+        });
+        let context_free = self.is_context_free();
+        let input_length = input.chars().count();
+        let can_derive_input = |form: &str| {
+            if non_contracting && form.chars().count() > input_length {
+                return false;
+            }
+            // In a context-free grammar, terminals are never rewritten, so those before the first
+            // variable and after the last stay at the ends of every string derived
+            if context_free {
+                let prefix: String = form.chars().take_while(|&c| !is_variable(c)).collect();
+                let suffix: String = form
+                    .chars()
+                    .rev()
+                    .take_while(|&c| !is_variable(c))
+                    .collect::<Vec<char>>()
+                    .into_iter()
+                    .rev()
+                    .collect();
+                let terminal_count = form.chars().filter(|&c| !is_variable(c)).count();
+                if !input.starts_with(&prefix)
+                    || !input.ends_with(&suffix)
+                    || terminal_count > input_length
+                {
+                    return false;
+                }
+            }
+            true
+        };
+
+        // Each node is a sentential form, the index of the form it was derived from, and its depth
+        let start = self.start_symbol.to_string();
+        let mut nodes: Vec<(String, Option<usize>, usize)> = vec![(start.clone(), None, 0)];
+        let mut visited: HashSet<String> = std::iter::once(start).collect();
+        let mut queue: VecDeque<usize> = std::iter::once(0).collect();
+        let mut limit_reached = false;
+
+        while let Some(index) = queue.pop_front() {
+            let (form, _, depth) = &nodes[index];
+            if form == input {
+                let mut derivation = Vec::new();
+                let mut current = Some(index);
+                while let Some(i) = current {
+                    derivation.push(nodes[i].0.clone());
+                    current = nodes[i].1;
+                }
+                derivation.reverse();
+                return DerivationResult::Derived(derivation);
+            }
+            if *depth >= limits.max_depth {
+                limit_reached = true;
+                continue;
+            }
+
+            let depth = *depth;
+            let mut derived = Vec::new();
+            for production in &self.productions {
+                if production.lhs.is_empty() {
+                    continue;
+                }
+                // Occurrences of the left side may overlap, so each position is checked
+                let positions = form
+                    .char_indices()
+                    .map(|(position, _)| position)
+                    .filter(|&position| form[position..].starts_with(&production.lhs));
+                for position in positions {
+                    let next = format!(
+                        "{}{}{}",
+                        &form[..position],
+                        production.rhs,
+                        &form[position + production.lhs.len()..]
+                    );
+                    if can_derive_input(&next) && !visited.contains(&next) {
+                        visited.insert(next.clone());
+                        derived.push(next);
+                    }
+                }
+            }
+
+            for next in derived {
+                if nodes.len() >= limits.max_nodes {
+                    return DerivationResult::LimitReached;
+                }
+                nodes.push((next, Some(index), depth + 1));
+                queue.push_back(nodes.len() - 1);
+            }
+        }
+
+        if limit_reached {
+            DerivationResult::LimitReached
+        } else {
+            DerivationResult::NotDerivable
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use super::{is_variable, Grammar, Production};
+
+use std::fmt;
+
+/// The most restrictive class of the Chomsky hierarchy containing a grammar
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GrammarType {
+    /// Regular, with every right side being terminals optionally followed by one variable
+    RightLinear,
+    /// Regular, with every right side being terminals optionally preceded by one variable
+    LeftLinear,
+    /// Every left side is a single variable
+    ContextFree,
+    /// No production shortens the string, except possibly the start symbol deriving
+    /// the empty string when it appears on no right side
+    ContextSensitive,
+    Unrestricted,
+}
+
+impl GrammarType {
+    pub fn is_regular(self) -> bool {
+        matches!(self, Self::RightLinear | Self::LeftLinear)
+    }
+}
+
+impl fmt::Display for GrammarType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Self::RightLinear => "regular (right-linear)",
+            Self::LeftLinear => "regular (left-linear)",
+            Self::ContextFree => "context-free",
+            Self::ContextSensitive => "context-sensitive",
+            Self::Unrestricted => "unrestricted",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Grammar {
+    /// Returns the most restrictive type of the grammar,
+    /// preferring right-linear for grammars which are also left-linear
+    pub fn classify(&self) -> GrammarType {
+        if !self.productions.iter().all(Production::is_valid) {
+            GrammarType::Unrestricted
+        } else if self.is_right_linear() {
+            GrammarType::RightLinear
+        } else if self.is_left_linear() {
+            GrammarType::LeftLinear
+        } else if self.is_context_free() {
+            GrammarType::ContextFree
+        } else if self.is_context_sensitive() {
+            GrammarType::ContextSensitive
+        } else {
+            GrammarType::Unrestricted
+        }
+    }
+
+    pub fn is_right_linear(&self) -> bool {
+        self.productions.iter().all(|production| {
+            let rhs: Vec<char> = production.rhs.chars().collect();
+            let terminals = match rhs.last() {
+                Some(&last) if is_variable(last) => &rhs[..rhs.len() - 1],
+                _ => &rhs[..],
+            };
+            production.single_variable_lhs().is_some()
+                && !terminals.iter().any(|&symbol| is_variable(symbol))
+        })
+    }
+
+    pub fn is_left_linear(&self) -> bool {
+        self.productions.iter().all(|production| {
+            let rhs: Vec<char> = production.rhs.chars().collect();
+            let terminals = match rhs.first() {
+                Some(&first) if is_variable(first) => &rhs[1..],
+                _ => &rhs[..],
+            };
+            production.single_variable_lhs().is_some()
+                && !terminals.iter().any(|&symbol| is_variable(symbol))
+        })
+    }
+
+    pub fn is_context_free(&self) -> bool {
+        self.productions
+            .iter()
+            .all(|production| production.single_variable_lhs().is_some())
+    }
+
+    pub fn is_context_sensitive(&self) -> bool {
+        let start_on_right = self
+            .productions
+            .iter()
+            .any(|production| production.rhs.contains(self.start_symbol));
+
+        self.productions.iter().all(|production| {
+            let is_start_to_empty = production.is_empty()
+                && production.single_variable_lhs() == Some(self.start_symbol);
+            production.lhs.chars().count() <= production.rhs.chars().count()
+                || (is_start_to_empty && !start_on_right)
+        })
+    }
+}
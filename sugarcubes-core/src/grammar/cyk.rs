@@ -0,0 +1,198 @@
+use super::{is_variable, Grammar, ParseTree};
+
+use std::collections::BTreeMap;
+
+// How a variable derives the substring of a cell
+#[derive(Copy, Clone, Debug)]
+enum CellDerivation {
+    // A production to the single terminal of the substring
+    Terminal,
+    // A production to two variables, deriving the substring split after the given length
+    Split(usize, char, char),
+}
+
+/// The triangular table of the CYK algorithm, recording which variables derive each substring.
+/// The table may be filled one cell at a time, shortest substrings first.
+#[derive(Clone, Debug)]
+pub struct CykTable {
+    grammar: Grammar,
+    input: Vec<char>,
+    // Indexed by substring length minus one, then start position
+    cells: Vec<Vec<BTreeMap<char, CellDerivation>>>,
+    filled: usize,
+}
+
+impl Grammar {
+    /// Returns whether every production has the form A → BC or A → a,
+    /// except for the start symbol deriving the empty string if it appears on no right side
+    pub fn is_chomsky_normal_form(&self) -> bool {
+        let start_on_right = self
+            .productions
+            .iter()
+            .any(|production| production.rhs.contains(self.start_symbol));
+
+        self.productions.iter().all(|production| {
+            let rhs: Vec<char> = production.rhs.chars().collect();
+            production.single_variable_lhs().is_some()
+                && match rhs.as_slice() {
+                    [] => {
+                        production.single_variable_lhs() == Some(self.start_symbol)
+                            && !start_on_right
+                    }
+                    [terminal] => !is_variable(*terminal),
+                    [first, second] => is_variable(*first) && is_variable(*second),
+                    _ => false,
+                }
+        })
+    }
+
+    /// Tests whether the grammar derives a string with the CYK algorithm,
+    /// returning the complete table, or None if the grammar is not in Chomsky normal form
+    pub fn cyk(&self, input: &str) -> Option<CykTable> {
+        let mut table = CykTable::new(self, input)?;
+        table.fill();
+        Some(table)
+    }
+}
+
+impl CykTable {
+    /// Creates an empty table, or returns None if the grammar is not in Chomsky normal form
+    pub fn new(grammar: &Grammar, input: &str) -> Option<Self> {
+        if !grammar.is_chomsky_normal_form() {
+            return None;
+        }
+
+        let input: Vec<char> = input.chars().collect();
+        let cells = (1..=input.len())
+            .map(|length| vec![BTreeMap::new(); input.len() - length + 1])
+            .collect();
+        Some(Self {
+            grammar: grammar.clone(),
+            input,
+            cells,
+            filled: 0,
+        })
+    }
+
+    pub fn input(&self) -> &[char] {
+        &self.input
+    }
+
+    /// Returns the variables deriving the substring with the given start and length
+    pub fn cell(&self, start: usize, length: usize) -> Vec<char> {
+        self.cells[length - 1][start].keys().cloned().collect()
+    }
+
+    /// Returns whether the cell with the given start and length has been filled
+    pub fn is_filled(&self, start: usize, length: usize) -> bool {
+        self.cell_index(start, length) < self.filled
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.filled == self.cell_count()
+    }
+
+    /// Fills the next cell, returning its start and length, or None if the table is complete
+    pub fn fill_next_cell(&mut self) -> Option<(usize, usize)> {
+        let (start, length) = self.cell_at(self.filled)?;
+
+        let mut cell = BTreeMap::new();
+        for production in &self.grammar.productions {
+            let variable = match production.single_variable_lhs() {
+                Some(variable) => variable,
+                None => continue,
+            };
+            let rhs: Vec<char> = production.rhs.chars().collect();
+            match rhs.as_slice() {
+                [terminal] if length == 1 && *terminal == self.input[start] => {
+                    cell.entry(variable).or_insert(CellDerivation::Terminal);
+                }
+                [first, second] => {
+                    for split in 1..length {
+                        let left = &self.cells[split - 1][start];
+                        let right = &self.cells[length - split - 1][start + split];
+                        if left.contains_key(first) && right.contains_key(second) {
+                            cell.entry(variable)
+                                .or_insert(CellDerivation::Split(split, *first, *second));
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.cells[length - 1][start] = cell;
+        self.filled += 1;
+        Some((start, length))
+    }
+
+    pub fn fill(&mut self) {
+        while self.fill_next_cell().is_some() {}
+    }
+
+    /// Returns whether the start symbol derives the input, once the table is complete
+    pub fn accepts(&self) -> bool {
+        if !self.is_complete() {
+            return false;
+        }
+
+        if self.input.is_empty() {
+            self.grammar
+                .productions_of(self.grammar.start_symbol)
+                .any(|production| production.is_empty())
+        } else {
+            self.cells[self.input.len() - 1][0].contains_key(&self.grammar.start_symbol)
+        }
+    }
+
+    /// Returns a parse tree of the input, if the table is complete and the input is accepted
+    pub fn parse_tree(&self) -> Option<ParseTree> {
+        if !self.accepts() {
+            return None;
+        }
+
+        if self.input.is_empty() {
+            Some(ParseTree::leaf(self.grammar.start_symbol))
+        } else {
+            Some(self.subtree(self.grammar.start_symbol, 0, self.input.len()))
+        }
+    }
+
+    fn subtree(&self, variable: char, start: usize, length: usize) -> ParseTree {
+        match self.cells[length - 1][start][&variable] {
+            CellDerivation::Terminal => {
+                ParseTree::new(variable, vec![ParseTree::leaf(self.input[start])])
+            }
+            CellDerivation::Split(split, first, second) => ParseTree::new(
+                variable,
+                vec![
+                    self.subtree(first, start, split),
+                    self.subtree(second, start + split, length - split),
+                ],
+            ),
+        }
+    }
+
+    fn cell_count(&self) -> usize {
+        self.input.len() * (self.input.len() + 1) / 2
+    }
+
+    // Cells are filled in order of increasing length, then increasing start
+    fn cell_index(&self, start: usize, length: usize) -> usize {
+        let shorter_cells: usize = (1..length)
+            .map(|shorter| self.input.len() - shorter + 1)
+            .sum();
+        shorter_cells + start
+    }
+
+    fn cell_at(&self, mut index: usize) -> Option<(usize, usize)> {
+        for (length, row) in (1..).zip(&self.cells) {
+            if index < row.len() {
+                return Some((index, length));
+            }
+            index -= row.len();
+        }
+        None
+    }
+}
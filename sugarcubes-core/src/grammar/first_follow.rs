@@ -0,0 +1,106 @@
+use super::{is_variable, Grammar, EMPTY_STRING_SYMBOL};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The symbol marking the end of the input, which follows the start symbol
+pub const END_MARKER: char = '$';
+
+impl Grammar {
+    /// Returns the terminals which can begin a string derived from each variable.
+    /// As in JFLAP, the set contains λ if the variable derives the empty string.
+    pub fn first_sets(&self) -> BTreeMap<char, BTreeSet<char>> {
+        let mut first: BTreeMap<char, BTreeSet<char>> = self
+            .variables()
+            .into_iter()
+            .map(|variable| (variable, BTreeSet::new()))
+            .collect();
+
+        loop {
+            let mut changed = false;
+            for production in &self.productions {
+                if let Some(variable) = production.single_variable_lhs() {
+                    let production_first = first_of(&production.rhs, &first);
+                    let variable_first = first.get_mut(&variable).unwrap();
+                    for symbol in production_first {
+                        changed |= variable_first.insert(symbol);
+                    }
+                }
+            }
+            if !changed {
+                return first;
+            }
+        }
+    }
+
+    /// Returns the terminals which can directly follow each variable in a derivation
+    /// from the start symbol, including the end marker $ after the start symbol
+    pub fn follow_sets(&self) -> BTreeMap<char, BTreeSet<char>> {
+        let first = self.first_sets();
+        let mut follow: BTreeMap<char, BTreeSet<char>> = self
+            .variables()
+            .into_iter()
+            .map(|variable| (variable, BTreeSet::new()))
+            .collect();
+        follow
+            .get_mut(&self.start_symbol)
+            .unwrap()
+            .insert(END_MARKER);
+
+        loop {
+            let mut changed = false;
+            for production in &self.productions {
+                let lhs = match production.single_variable_lhs() {
+                    Some(lhs) => lhs,
+                    None => continue,
+                };
+                for (i, symbol) in production.rhs.char_indices() {
+                    if !is_variable(symbol) {
+                        continue;
+                    }
+
+                    // Whatever can begin the rest of the right side follows the variable,
+                    // as does whatever follows the left side if the rest can be empty
+                    let rest = &production.rhs[i + symbol.len_utf8()..];
+                    let mut following = first_of(rest, &first);
+                    if following.remove(&EMPTY_STRING_SYMBOL) {
+                        following.extend(follow[&lhs].iter().copied());
+                    }
+                    let symbol_follow = follow.get_mut(&symbol).unwrap();
+                    for terminal in following {
+                        changed |= symbol_follow.insert(terminal);
+                    }
+                }
+            }
+            if !changed {
+                return follow;
+            }
+        }
+    }
+}
+
+/// Returns the terminals which can begin a string derived from the given symbols,
+/// containing λ if they can derive the empty string
+pub fn first_of(symbols: &str, first_sets: &BTreeMap<char, BTreeSet<char>>) -> BTreeSet<char> {
+    let mut first = BTreeSet::new();
+    for symbol in symbols.chars() {
+        if !is_variable(symbol) {
+            first.insert(symbol);
+            return first;
+        }
+
+        let symbol_first = match first_sets.get(&symbol) {
+            Some(symbol_first) => symbol_first,
+            None => return first,
+        };
+        first.extend(
+            symbol_first
+                .iter()
+                .filter(|&&terminal| terminal != EMPTY_STRING_SYMBOL),
+        );
+        if !symbol_first.contains(&EMPTY_STRING_SYMBOL) {
+            return first;
+        }
+    }
+    first.insert(EMPTY_STRING_SYMBOL);
+    first
+}
@@ -0,0 +1,291 @@
+use super::{is_variable, Grammar, Production, TransformError, TransformationKind};
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// The most productions the conversion may produce, as substitution can multiply them quickly
+pub const MAX_GREIBACH_PRODUCTIONS: usize = 2000;
+
+/// A rewrite applied while converting a grammar to Greibach normal form
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GreibachRewrite {
+    /// The grammar was simplified, as before converting to Chomsky normal form
+    Simplify(TransformationKind),
+    /// Productions of `lhs` starting with `variable` were replaced,
+    /// once for each production of `variable`
+    Substitute { lhs: char, variable: char },
+    /// Productions of `variable` starting with `variable` were replaced
+    /// by right recursive productions of `new_variable`
+    RemoveLeftRecursion { variable: char, new_variable: char },
+    /// A terminal after the first symbol of a right side was replaced by a variable deriving it
+    ReplaceTerminal { terminal: char, variable: char },
+}
+
+impl fmt::Display for GreibachRewrite {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Simplify(kind) => write!(f, "{}", kind),
+            Self::Substitute { lhs, variable } => write!(
+                f,
+                "Substitute the productions of {} into those of {} starting with it",
+                variable, lhs
+            ),
+            Self::RemoveLeftRecursion {
+                variable,
+                new_variable,
+            } => write!(
+                f,
+                "Remove left recursion of {} with the new variable {}",
+                variable, new_variable
+            ),
+            Self::ReplaceTerminal { terminal, variable } => write!(
+                f,
+                "Replace {} after the first symbol with the new variable {}",
+                terminal, variable
+            ),
+        }
+    }
+}
+
+/// The result of converting a grammar to Greibach normal form
+#[derive(Clone, Debug, PartialEq)]
+pub struct GreibachConversion {
+    pub grammar: Grammar,
+    /// The rewrites applied, in order
+    pub log: Vec<GreibachRewrite>,
+}
+
+impl Grammar {
+    /// Returns whether every production has the form A → aα, where α is a string of variables,
+    /// except for the start symbol deriving the empty string if it appears on no right side
+    pub fn is_greibach_normal_form(&self) -> bool {
+        let start_on_right = self
+            .productions
+            .iter()
+            .any(|production| production.rhs.contains(self.start_symbol));
+
+        self.productions.iter().all(|production| {
+            let mut rhs = production.rhs.chars();
+            production.single_variable_lhs().is_some()
+                && match rhs.next() {
+                    None => {
+                        production.single_variable_lhs() == Some(self.start_symbol)
+                            && !start_on_right
+                    }
+                    Some(first) => !is_variable(first) && rhs.all(is_variable),
+                }
+        })
+    }
+
+    /// Converts a context-free grammar to Greibach normal form, generating the same language.
+    /// After simplifying the grammar, its variables are ordered so that each production
+    /// of a variable starts with a terminal or a later variable, eliminating left recursion
+    /// along the way. Leading variables are then substituted from the last variable backwards.
+    pub fn greibach_normal_form(&self) -> Result<GreibachConversion, TransformError> {
+        let mut log = Vec::new();
+        let steps = self.simplification_steps()?;
+        for step in &steps {
+            log.push(GreibachRewrite::Simplify(step.kind));
+        }
+        let simplified = &steps.last().unwrap().grammar;
+
+        // The start symbol is first, then variables in the order their productions appear
+        let mut order = vec![simplified.start_symbol];
+        let mut rules: BTreeMap<char, Vec<String>> = BTreeMap::new();
+        rules.insert(simplified.start_symbol, Vec::new());
+        let mut derives_empty = false;
+        for production in &simplified.productions {
+            let lhs = production.single_variable_lhs().unwrap();
+            if production.is_empty() {
+                // After simplifying, this is the start symbol, which appears on no right side
+                derives_empty = true;
+                continue;
+            }
+            rules
+                .entry(lhs)
+                .or_insert_with(|| {
+                    order.push(lhs);
+                    Vec::new()
+                })
+                .push(production.rhs.clone());
+        }
+
+        let mut used = simplified.variables();
+        let mut new_variables = Vec::new();
+
+        for i in 0..order.len() {
+            let variable = order[i];
+            for &earlier in &order[..i] {
+                if substitute_leading(&mut rules, variable, earlier)? {
+                    log.push(GreibachRewrite::Substitute {
+                        lhs: variable,
+                        variable: earlier,
+                    });
+                }
+            }
+
+            // Replace A → Aα | β with A → β | βZ and Z → α | αZ
+            let (recursive, other): (Vec<String>, Vec<String>) = rules[&variable]
+                .iter()
+                .cloned()
+                .partition(|rhs| rhs.starts_with(variable));
+            if !recursive.is_empty() {
+                let new_variable = self.fresh_variable(&used)?;
+                used.insert(new_variable);
+                new_variables.push(new_variable);
+
+                let with_new_variable = |right_sides: Vec<String>| {
+                    let mut result = right_sides.clone();
+                    result.extend(
+                        right_sides
+                            .into_iter()
+                            .map(|rhs| format!("{}{}", rhs, new_variable)),
+                    );
+                    result
+                };
+                let suffixes = recursive
+                    .into_iter()
+                    .map(|rhs| rhs.chars().skip(1).collect())
+                    .collect();
+                rules.insert(variable, with_new_variable(other));
+                rules.insert(new_variable, with_new_variable(suffixes));
+                log.push(GreibachRewrite::RemoveLeftRecursion {
+                    variable,
+                    new_variable,
+                });
+            }
+        }
+
+        // The last variable's productions all start with a terminal, so substituting backwards
+        // makes every production start with a terminal. New variables are substituted last.
+        let substitution_order: Vec<char> = order
+            .iter()
+            .rev()
+            .chain(new_variables.iter())
+            .copied()
+            .collect();
+        loop {
+            let mut changed = false;
+            for &variable in &substitution_order {
+                let leading: BTreeSet<char> = rules[&variable]
+                    .iter()
+                    .filter_map(|rhs| rhs.chars().next())
+                    .filter(|&symbol| is_variable(symbol))
+                    .collect();
+                for symbol in leading {
+                    let symbol_in_normal_form = rules[&symbol]
+                        .iter()
+                        .all(|rhs| rhs.chars().next().is_some_and(|first| !is_variable(first)));
+                    if symbol_in_normal_form && substitute_leading(&mut rules, variable, symbol)? {
+                        log.push(GreibachRewrite::Substitute {
+                            lhs: variable,
+                            variable: symbol,
+                        });
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        // Terminals after the first symbol are replaced by variables
+        let mut terminal_variables: BTreeMap<char, char> = BTreeMap::new();
+        let mut terminal_order = Vec::new();
+        for &variable in &substitution_order {
+            for rhs in rules.get_mut(&variable).unwrap() {
+                let mut replaced = String::new();
+                for (i, symbol) in rhs.chars().enumerate() {
+                    if i == 0 || is_variable(symbol) {
+                        replaced.push(symbol);
+                    } else if let Some(&terminal_variable) = terminal_variables.get(&symbol) {
+                        replaced.push(terminal_variable);
+                    } else {
+                        let terminal_variable = self.fresh_variable(&used)?;
+                        used.insert(terminal_variable);
+                        terminal_variables.insert(symbol, terminal_variable);
+                        terminal_order.push(terminal_variable);
+                        log.push(GreibachRewrite::ReplaceTerminal {
+                            terminal: symbol,
+                            variable: terminal_variable,
+                        });
+                        replaced.push(terminal_variable);
+                    }
+                }
+                *rhs = replaced;
+            }
+        }
+        for (&terminal, &variable) in &terminal_variables {
+            rules.insert(variable, vec![terminal.to_string()]);
+        }
+
+        let mut grammar = Grammar::new(Vec::new(), simplified.start_symbol);
+        if derives_empty {
+            grammar.add_production(Production::new(&simplified.start_symbol.to_string(), ""));
+        }
+        for variable in order
+            .iter()
+            .chain(new_variables.iter())
+            .chain(terminal_order.iter())
+        {
+            for rhs in &rules[variable] {
+                grammar.add_production(Production::new(&variable.to_string(), rhs));
+            }
+        }
+
+        // Substitution can leave variables which are no longer reachable
+        let reachable = grammar.remove_useless_symbols();
+        if reachable != grammar {
+            log.push(GreibachRewrite::Simplify(
+                TransformationKind::RemoveUselessSymbols,
+            ));
+        }
+
+        Ok(GreibachConversion {
+            grammar: reachable,
+            log,
+        })
+    }
+}
+
+// Replaces each right side of `lhs` starting with `variable` by every production of `variable`
+// followed by the rest of that right side, returning whether any were replaced
+fn substitute_leading(
+    rules: &mut BTreeMap<char, Vec<String>>,
+    lhs: char,
+    variable: char,
+) -> Result<bool, TransformError> {
+    if !rules[&lhs].iter().any(|rhs| rhs.starts_with(variable)) {
+        return Ok(false);
+    }
+
+    let substitutes = rules[&variable].clone();
+    let mut substituted: Vec<String> = Vec::new();
+    let mut seen = BTreeSet::new();
+    for rhs in &rules[&lhs] {
+        let replacements = if rhs.starts_with(variable) {
+            let rest: String = rhs.chars().skip(1).collect();
+            substitutes
+                .iter()
+                .map(|substitute| format!("{}{}", substitute, rest))
+                .collect()
+        } else {
+            vec![rhs.clone()]
+        };
+        for replacement in replacements {
+            if seen.insert(replacement.clone()) {
+                substituted.push(replacement);
+            }
+        }
+        if substituted.len() > MAX_GREIBACH_PRODUCTIONS {
+            return Err(TransformError::TooManyProductions);
+        }
+    }
+    rules.insert(lhs, substituted);
+
+    if rules.values().map(Vec::len).sum::<usize>() > MAX_GREIBACH_PRODUCTIONS {
+        return Err(TransformError::TooManyProductions);
+    }
+    Ok(true)
+}
@@ -0,0 +1,194 @@
+use super::{first_of, is_variable, Grammar, Production, EMPTY_STRING_SYMBOL, END_MARKER};
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// What an LL(1) parser does in one step
+#[derive(Clone, Debug, PartialEq)]
+pub enum LlAction {
+    /// Replace the variable on top of the stack with the right side of the production
+    Predict(Production),
+    /// Pop the terminal on top of the stack, which is the next input symbol
+    Match(char),
+    Accept,
+    Reject,
+}
+
+impl fmt::Display for LlAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Predict(production) => write!(f, "Predict {}", production),
+            Self::Match(terminal) => write!(f, "Match {}", terminal),
+            Self::Accept => write!(f, "Accept"),
+            Self::Reject => write!(f, "Reject"),
+        }
+    }
+}
+
+/// A configuration of an LL(1) parser, and the action taken from it
+#[derive(Clone, Debug, PartialEq)]
+pub struct LlParseStep {
+    /// The stack, with its top first
+    pub stack: String,
+    /// The unread input, ending with the end marker
+    pub remaining: String,
+    pub action: LlAction,
+}
+
+/// The LL(1) parse table of a context-free grammar, choosing the production to predict
+/// from the variable on top of the stack and the next input symbol
+#[derive(Clone, Debug)]
+pub struct LlParseTable {
+    grammar: Grammar,
+    first_sets: BTreeMap<char, BTreeSet<char>>,
+    follow_sets: BTreeMap<char, BTreeSet<char>>,
+    // Indices of the productions in each cell, keyed by variable and lookahead
+    entries: BTreeMap<(char, char), Vec<usize>>,
+}
+
+impl Grammar {
+    /// Returns whether the grammar's LL(1) parse table has at most one production in each cell
+    pub fn is_ll1(&self) -> bool {
+        LlParseTable::new(self).is_some_and(|table| table.conflicts().is_empty())
+    }
+}
+
+impl LlParseTable {
+    /// Builds the parse table, or returns None if the grammar is not context-free
+    pub fn new(grammar: &Grammar) -> Option<Self> {
+        if !grammar.is_context_free() {
+            return None;
+        }
+
+        let first_sets = grammar.first_sets();
+        let follow_sets = grammar.follow_sets();
+        let mut entries: BTreeMap<(char, char), Vec<usize>> = BTreeMap::new();
+        for (i, production) in grammar.productions.iter().enumerate() {
+            let variable = production.single_variable_lhs().unwrap();
+            let mut lookaheads = first_of(&production.rhs, &first_sets);
+            // A production deriving the empty string is predicted on whatever follows its variable
+            if lookaheads.remove(&EMPTY_STRING_SYMBOL) {
+                lookaheads.extend(follow_sets[&variable].iter().copied());
+            }
+            for lookahead in lookaheads {
+                let entry = entries.entry((variable, lookahead)).or_default();
+                if !entry.contains(&i) {
+                    entry.push(i);
+                }
+            }
+        }
+
+        Some(Self {
+            grammar: grammar.clone(),
+            first_sets,
+            follow_sets,
+            entries,
+        })
+    }
+
+    pub fn grammar(&self) -> &Grammar {
+        &self.grammar
+    }
+
+    pub fn first_sets(&self) -> &BTreeMap<char, BTreeSet<char>> {
+        &self.first_sets
+    }
+
+    pub fn follow_sets(&self) -> &BTreeMap<char, BTreeSet<char>> {
+        &self.follow_sets
+    }
+
+    /// Returns the rows of the table, with the start symbol first
+    pub fn variables(&self) -> Vec<char> {
+        let start_symbol = self.grammar.start_symbol;
+        std::iter::once(start_symbol)
+            .chain(
+                self.grammar
+                    .variables()
+                    .into_iter()
+                    .filter(|&variable| variable != start_symbol),
+            )
+            .collect()
+    }
+
+    /// Returns the columns of the table, the terminals followed by the end marker
+    pub fn lookaheads(&self) -> Vec<char> {
+        self.grammar
+            .terminals()
+            .into_iter()
+            .chain(std::iter::once(END_MARKER))
+            .collect()
+    }
+
+    /// Returns the productions in the cell of a variable and lookahead symbol
+    pub fn entry(&self, variable: char, lookahead: char) -> Vec<&Production> {
+        self.entries
+            .get(&(variable, lookahead))
+            .map(|indices| {
+                indices
+                    .iter()
+                    .map(|&i| &self.grammar.productions[i])
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the variable and lookahead of each cell with more than one production
+    pub fn conflicts(&self) -> Vec<(char, char)> {
+        self.entries
+            .iter()
+            .filter(|(_, indices)| indices.len() > 1)
+            .map(|(&cell, _)| cell)
+            .collect()
+    }
+
+    /// Parses a string, returning every step up to accepting or rejecting it,
+    /// or None if the table has conflicts
+    pub fn parse(&self, input: &str) -> Option<Vec<LlParseStep>> {
+        if !self.conflicts().is_empty() {
+            return None;
+        }
+
+        let input: Vec<char> = input.chars().chain(std::iter::once(END_MARKER)).collect();
+        let mut position = 0;
+        let mut stack = vec![END_MARKER, self.grammar.start_symbol];
+        let mut steps = Vec::new();
+
+        loop {
+            let top = *stack.last().unwrap();
+            let next = input[position];
+            let action = if top == END_MARKER && next == END_MARKER {
+                LlAction::Accept
+            } else if is_variable(top) {
+                match self.entries.get(&(top, next)) {
+                    Some(indices) => {
+                        LlAction::Predict(self.grammar.productions[indices[0]].clone())
+                    }
+                    None => LlAction::Reject,
+                }
+            } else if top == next {
+                LlAction::Match(next)
+            } else {
+                LlAction::Reject
+            };
+
+            steps.push(LlParseStep {
+                stack: stack.iter().rev().collect(),
+                remaining: input[position..].iter().collect(),
+                action: action.clone(),
+            });
+
+            match action {
+                LlAction::Predict(production) => {
+                    stack.pop();
+                    stack.extend(production.rhs.chars().rev());
+                }
+                LlAction::Match(_) => {
+                    stack.pop();
+                    position += 1;
+                }
+                LlAction::Accept | LlAction::Reject => return Some(steps),
+            }
+        }
+    }
+}
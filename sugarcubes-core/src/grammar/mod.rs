@@ -0,0 +1,107 @@
+pub mod brute_force;
+pub mod classify;
+pub mod cyk;
+pub mod first_follow;
+pub mod greibach;
+pub mod ll1;
+pub mod parse_tree;
+pub mod production;
+pub mod pushdown;
+pub mod regular;
+pub mod slr1;
+pub mod transform;
+
+pub use brute_force::{DerivationLimits, DerivationResult, DERIVES_SYMBOL};
+pub use classify::GrammarType;
+pub use cyk::CykTable;
+pub use first_follow::{first_of, END_MARKER};
+pub use greibach::{GreibachConversion, GreibachRewrite};
+pub use ll1::{LlAction, LlParseStep, LlParseTable};
+pub use parse_tree::ParseTree;
+pub use production::Production;
+pub use pushdown::PushdownConstruction;
+pub use slr1::{LrItem, SlrAction, SlrConflict, SlrParseAction, SlrParseStep, SlrParseTable};
+pub use transform::{TransformError, TransformationKind, TransformationStep};
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// The symbol used to display the empty string, as in JFLAP
+pub const EMPTY_STRING_SYMBOL: char = 'λ';
+pub const PRODUCTION_ARROW: char = '→';
+/// The start symbol of a new grammar
+pub const DEFAULT_START_SYMBOL: char = 'S';
+
+/// Returns whether a symbol is a variable, which like in JFLAP means an uppercase letter.
+/// Every other symbol is a terminal.
+pub fn is_variable(symbol: char) -> bool {
+    symbol.is_ascii_uppercase()
+}
+
+/// A formal grammar, whose variables and terminals are those appearing in its productions
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grammar {
+    pub productions: Vec<Production>,
+    pub start_symbol: char,
+}
+
+impl Default for Grammar {
+    fn default() -> Self {
+        Self {
+            productions: Vec::new(),
+            start_symbol: DEFAULT_START_SYMBOL,
+        }
+    }
+}
+
+impl Grammar {
+    pub fn new(productions: Vec<Production>, start_symbol: char) -> Self {
+        Self {
+            productions,
+            start_symbol,
+        }
+    }
+
+    /// Returns the variables of the grammar, including the start symbol
+    pub fn variables(&self) -> BTreeSet<char> {
+        self.symbols()
+            .filter(|&symbol| is_variable(symbol))
+            .chain(std::iter::once(self.start_symbol))
+            .collect()
+    }
+
+    pub fn terminals(&self) -> BTreeSet<char> {
+        self.symbols()
+            .filter(|&symbol| !is_variable(symbol))
+            .collect()
+    }
+
+    /// Returns the productions whose left side is exactly the given variable
+    pub fn productions_of(&self, variable: char) -> impl Iterator<Item = &Production> {
+        self.productions
+            .iter()
+            .filter(move |production| production.single_variable_lhs() == Some(variable))
+    }
+
+    /// Adds a production unless the grammar already has it
+    pub fn add_production(&mut self, production: Production) {
+        if !self.productions.contains(&production) {
+            self.productions.push(production);
+        }
+    }
+
+    fn symbols(&self) -> impl Iterator<Item = char> + '_ {
+        self.productions
+            .iter()
+            .flat_map(|production| production.lhs.chars().chain(production.rhs.chars()))
+    }
+}
+
+impl fmt::Display for Grammar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for production in &self.productions {
+            writeln!(f, "{}", production)?;
+        }
+        Ok(())
+    }
+}
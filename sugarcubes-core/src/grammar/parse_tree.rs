@@ -0,0 +1,74 @@
+use super::{is_variable, EMPTY_STRING_SYMBOL};
+
+use std::fmt;
+
+/// A derivation of a string from a symbol, where each variable's children
+/// are the right side of the production applied to it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseTree {
+    pub symbol: char,
+    pub children: Vec<ParseTree>,
+}
+
+impl ParseTree {
+    pub fn new(symbol: char, children: Vec<ParseTree>) -> Self {
+        Self { symbol, children }
+    }
+
+    pub fn leaf(symbol: char) -> Self {
+        Self::new(symbol, Vec::new())
+    }
+
+    /// Returns the string derived by the tree, i.e. its terminal leaves from left to right
+    pub fn yielded(&self) -> String {
+        if self.children.is_empty() {
+            if is_variable(self.symbol) {
+                String::new()
+            } else {
+                self.symbol.to_string()
+            }
+        } else {
+            self.children.iter().map(ParseTree::yielded).collect()
+        }
+    }
+
+    /// Returns the number of levels in the tree
+    pub fn depth(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(ParseTree::depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of leaves in the tree, counting variables deriving the empty string
+    pub fn width(&self) -> usize {
+        self.children
+            .iter()
+            .map(ParseTree::width)
+            .sum::<usize>()
+            .max(1)
+    }
+}
+
+/// Writes the tree in bracket notation, e.g. S(a S(λ) b)
+impl fmt::Display for ParseTree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.symbol)?;
+        if is_variable(self.symbol) {
+            write!(f, "(")?;
+            if self.children.is_empty() {
+                write!(f, "{}", EMPTY_STRING_SYMBOL)?;
+            }
+            for (i, child) in self.children.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", child)?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
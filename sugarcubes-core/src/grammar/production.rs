@@ -0,0 +1,52 @@
+use super::{is_variable, EMPTY_STRING_SYMBOL, PRODUCTION_ARROW};
+
+use std::fmt;
+
+/// A rule replacing its left side with its right side, where each character is a symbol
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Production {
+    pub lhs: String,
+    pub rhs: String,
+}
+
+impl Production {
+    pub fn new(lhs: &str, rhs: &str) -> Self {
+        Self {
+            lhs: lhs.to_string(),
+            rhs: rhs.to_string(),
+        }
+    }
+
+    /// Returns whether the production derives the empty string, i.e. its right side is empty
+    pub fn is_empty(&self) -> bool {
+        self.rhs.is_empty()
+    }
+
+    /// Returns the left side if it is exactly one variable
+    pub fn single_variable_lhs(&self) -> Option<char> {
+        let mut chars = self.lhs.chars();
+        match (chars.next(), chars.next()) {
+            (Some(symbol), None) if is_variable(symbol) => Some(symbol),
+            _ => None,
+        }
+    }
+
+    /// Every production must replace at least one variable
+    pub fn is_valid(&self) -> bool {
+        self.lhs.chars().any(is_variable)
+    }
+}
+
+impl fmt::Display for Production {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            write!(
+                f,
+                "{} {} {}",
+                self.lhs, PRODUCTION_ARROW, EMPTY_STRING_SYMBOL
+            )
+        } else {
+            write!(f, "{} {} {}", self.lhs, PRODUCTION_ARROW, self.rhs)
+        }
+    }
+}
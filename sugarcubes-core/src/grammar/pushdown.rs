@@ -0,0 +1,98 @@
+use super::{Grammar, TransformError};
+use crate::automata::{
+    pushdown_automaton::{unused_stack_symbol, PushdownAutomaton, PushdownAutomatonTransition},
+    EMPTY_STRING,
+};
+
+use std::fmt;
+
+/// The ways of building a pushdown automaton which accepts the language of a grammar
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PushdownConstruction {
+    /// Top-down: expand the variable on top of the stack, then match terminals against the input
+    Ll,
+    /// Bottom-up: shift input symbols onto the stack, then reduce right sides to their variables
+    Lr,
+}
+
+impl fmt::Display for PushdownConstruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Ll => write!(f, "LL"),
+            Self::Lr => write!(f, "LR"),
+        }
+    }
+}
+
+impl Grammar {
+    /// Builds a three-state pushdown automaton accepting the language of the grammar
+    /// by final state, laid out as in JFLAP.
+    ///
+    /// The LL construction pushes the start symbol, then repeatedly replaces the variable
+    /// on top of the stack with the right side of one of its productions
+    /// or pops a terminal matching the next input symbol.
+    /// The LR construction shifts input symbols onto the stack, and replaces the reversed
+    /// right side of a production on top of the stack with its variable,
+    /// accepting once only the start symbol is left.
+    pub fn to_pushdown_automaton(
+        &self,
+        construction: PushdownConstruction,
+    ) -> Result<PushdownAutomaton, TransformError> {
+        if !self.is_context_free() {
+            return Err(TransformError::NotContextFree);
+        }
+
+        let mut pda = PushdownAutomaton::default();
+        // The bottom of the stack must be distinguishable from the grammar's symbols
+        let symbols = self
+            .variables()
+            .into_iter()
+            .chain(self.terminals())
+            .collect();
+        pda.initial_stack_symbol = unused_stack_symbol(&symbols);
+        let bottom = pda.initial_stack_symbol.to_string();
+        let start = self.start_symbol.to_string();
+
+        let (q0, q1, q2) = (
+            pda.automaton.add_new_state(),
+            pda.automaton.add_new_state(),
+            pda.automaton.add_new_state(),
+        );
+        pda.automaton.set_initial(q0);
+        pda.automaton.set_final(q2, true);
+        let mut add = |from, to, symbol, pop: &str, push: &str| {
+            pda.automaton
+                .add_transition(PushdownAutomatonTransition::new(
+                    from, to, symbol, pop, push,
+                ))
+        };
+
+        match construction {
+            PushdownConstruction::Ll => {
+                add(q0, q1, EMPTY_STRING, &bottom, &(start + &bottom));
+                for production in &self.productions {
+                    add(q1, q1, EMPTY_STRING, &production.lhs, &production.rhs);
+                }
+                for terminal in self.terminals() {
+                    let terminal_string = terminal.to_string();
+                    add(q1, q1, terminal, &terminal_string, "");
+                }
+                add(q1, q2, EMPTY_STRING, &bottom, &bottom);
+            }
+            PushdownConstruction::Lr => {
+                for terminal in self.terminals() {
+                    add(q0, q0, terminal, "", &terminal.to_string());
+                }
+                // The last symbol of a right side is shifted last, so it is on top of the stack
+                for production in &self.productions {
+                    let reversed: String = production.rhs.chars().rev().collect();
+                    add(q0, q0, EMPTY_STRING, &reversed, &production.lhs);
+                }
+                add(q0, q1, EMPTY_STRING, &start, "");
+                add(q1, q2, EMPTY_STRING, &bottom, "");
+            }
+        }
+
+        Ok(pda)
+    }
+}
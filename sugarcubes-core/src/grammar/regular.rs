@@ -0,0 +1,148 @@
+use super::{is_variable, Grammar, Production, TransformError, DEFAULT_START_SYMBOL};
+use crate::automata::{
+    finite_automaton::{FiniteAutomaton, FiniteAutomatonTransition},
+    Transition, EMPTY_STRING,
+};
+
+use std::collections::{btree_map, BTreeMap, BTreeSet};
+
+impl Grammar {
+    /// Builds a finite automaton accepting the language of a right-linear grammar.
+    ///
+    /// Each variable becomes a state, the start symbol's being initial, and a single final state
+    /// is added. A production A → wB becomes a path from A to B reading w,
+    /// and a production A → w a path from A to the final state,
+    /// through new states if w has more than one symbol.
+    /// The returned map gives the variable of each state which has one.
+    pub fn to_finite_automaton(
+        &self,
+    ) -> Result<(FiniteAutomaton, BTreeMap<u32, char>), TransformError> {
+        if !self.is_right_linear() {
+            return Err(TransformError::NotRightLinear);
+        }
+
+        let mut fa = FiniteAutomaton::default();
+        let mut variables = BTreeMap::new();
+        let mut states = BTreeMap::new();
+        let others = self
+            .variables()
+            .into_iter()
+            .filter(|&variable| variable != self.start_symbol);
+        for variable in std::iter::once(self.start_symbol).chain(others) {
+            let state = fa.automaton.add_new_state();
+            states.insert(variable, state);
+            variables.insert(state, variable);
+        }
+        fa.automaton.set_initial(states[&self.start_symbol]);
+        let accept = fa.automaton.add_new_state();
+        fa.automaton.set_final(accept, true);
+
+        for production in &self.productions {
+            let from = states[&production.single_variable_lhs().unwrap()];
+            let mut terminals: Vec<char> = production.rhs.chars().collect();
+            let to = match terminals.last() {
+                Some(&last) if is_variable(last) => {
+                    terminals.pop();
+                    states[&last]
+                }
+                _ => accept,
+            };
+
+            if terminals.is_empty() {
+                fa.automaton
+                    .add_transition(FiniteAutomatonTransition::new(from, to, EMPTY_STRING));
+                continue;
+            }
+            let mut current = from;
+            for (i, &terminal) in terminals.iter().enumerate() {
+                let next = if i == terminals.len() - 1 {
+                    to
+                } else {
+                    fa.automaton.add_new_state()
+                };
+                fa.automaton
+                    .add_transition(FiniteAutomatonTransition::new(current, next, terminal));
+                current = next;
+            }
+        }
+
+        Ok((fa, variables))
+    }
+}
+
+impl FiniteAutomaton {
+    /// Builds a right-linear grammar generating the language of the automaton,
+    /// with a variable for each state and the initial state's variable as the start symbol.
+    /// A transition from p to q reading w is a production P → wQ (or P → Q if it reads nothing),
+    /// and each final state P has a production P → λ.
+    ///
+    /// A state whose name is a single uppercase letter, not already taken by another state,
+    /// is named by that variable. Otherwise the initial state is S if possible,
+    /// and other states take the first unused letters.
+    pub fn to_grammar(&self, names: &BTreeMap<u32, String>) -> Result<Grammar, TransformError> {
+        if let Some(symbol) = self
+            .alphabet()
+            .into_iter()
+            .find(|&symbol| is_variable(symbol))
+        {
+            return Err(TransformError::VariableAsTerminal(symbol));
+        }
+
+        let mut variables: BTreeMap<u32, char> = BTreeMap::new();
+        let mut used = BTreeSet::new();
+        for &state in self.automaton.states_iter() {
+            let mut chars = names
+                .get(&state)
+                .map(|name| name.chars())
+                .into_iter()
+                .flatten();
+            if let (Some(letter), None) = (chars.next(), chars.next()) {
+                if is_variable(letter) && used.insert(letter) {
+                    variables.insert(state, letter);
+                }
+            }
+        }
+        if let Some(initial) = self.automaton.initial() {
+            if !variables.contains_key(&initial) && used.insert(DEFAULT_START_SYMBOL) {
+                variables.insert(initial, DEFAULT_START_SYMBOL);
+            }
+        }
+        for &state in self.automaton.states_iter() {
+            if let btree_map::Entry::Vacant(entry) = variables.entry(state) {
+                let letter = ('A'..='Z')
+                    .find(|letter| !used.contains(letter))
+                    .ok_or(TransformError::OutOfVariables)?;
+                used.insert(letter);
+                entry.insert(letter);
+            }
+        }
+
+        let mut grammar = Grammar::default();
+        let initial = match self.automaton.initial() {
+            Some(initial) => initial,
+            None => return Ok(grammar),
+        };
+        grammar.start_symbol = variables[&initial];
+
+        // List the initial state's productions first, so the start symbol comes first
+        let states = std::iter::once(initial).chain(
+            self.automaton
+                .states_iter()
+                .copied()
+                .filter(|&state| state != initial),
+        );
+        for state in states {
+            let lhs = variables[&state].to_string();
+            for transition in self.automaton.transitions_from(state) {
+                let variable = variables[&transition.to()];
+                let rhs = format!("{}{}", transition.read(), variable);
+                grammar.add_production(Production::new(&lhs, &rhs));
+            }
+            if self.automaton.is_final(state) {
+                grammar.add_production(Production::new(&lhs, ""));
+            }
+        }
+
+        Ok(grammar)
+    }
+}
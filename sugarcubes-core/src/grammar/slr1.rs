@@ -0,0 +1,430 @@
+use super::{is_variable, Grammar, Production, END_MARKER};
+use crate::automata::finite_automaton::{FiniteAutomaton, FiniteAutomatonTransition};
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// The symbol marking how much of a production's right side an item has seen
+pub const ITEM_DOT: char = '·';
+
+/// An LR(0) item, a production of the augmented grammar with a position in its right side
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LrItem {
+    /// The index of the production in the augmented grammar
+    pub production: usize,
+    /// The number of right side symbols before the dot
+    pub dot: usize,
+}
+
+/// An entry in the action part of an SLR(1) parse table
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SlrAction {
+    /// Push the next input symbol and the given state
+    Shift(usize),
+    /// Pop the right side of the given production of the augmented grammar,
+    /// then push its left side and the state reached from the new top state
+    Reduce(usize),
+    Accept,
+}
+
+impl fmt::Display for SlrAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Shift(state) => write!(f, "s{}", state),
+            Self::Reduce(production) => write!(f, "r{}", production),
+            Self::Accept => write!(f, "acc"),
+        }
+    }
+}
+
+/// A cell of an SLR(1) parse table with more than one action
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SlrConflict {
+    ShiftReduce { state: usize, lookahead: char },
+    ReduceReduce { state: usize, lookahead: char },
+}
+
+impl SlrConflict {
+    /// Returns the state and lookahead of the conflicting cell
+    pub fn cell(&self) -> (usize, char) {
+        match *self {
+            Self::ShiftReduce { state, lookahead } | Self::ReduceReduce { state, lookahead } => {
+                (state, lookahead)
+            }
+        }
+    }
+}
+
+impl fmt::Display for SlrConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ShiftReduce { state, lookahead } => write!(
+                f,
+                "Shift/reduce conflict in state {} on {}",
+                state, lookahead
+            ),
+            Self::ReduceReduce { state, lookahead } => write!(
+                f,
+                "Reduce/reduce conflict in state {} on {}",
+                state, lookahead
+            ),
+        }
+    }
+}
+
+/// What an SLR(1) parser does in one step
+#[derive(Clone, Debug, PartialEq)]
+pub enum SlrParseAction {
+    Shift(usize),
+    /// Reduce by the production, then go to the state
+    Reduce(Production, usize),
+    Accept,
+    Reject,
+}
+
+impl fmt::Display for SlrParseAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Shift(state) => write!(f, "Shift {}", state),
+            Self::Reduce(production, state) => {
+                write!(f, "Reduce {}, go to {}", production, state)
+            }
+            Self::Accept => write!(f, "Accept"),
+            Self::Reject => write!(f, "Reject"),
+        }
+    }
+}
+
+/// A configuration of an SLR(1) parser, and the action taken from it
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlrParseStep {
+    /// The stack of states and symbols, with its top last
+    pub stack: String,
+    /// The unread input, ending with the end marker
+    pub remaining: String,
+    pub action: SlrParseAction,
+}
+
+/// The SLR(1) parse table of a context-free grammar, built from the DFA of LR(0) item sets
+/// of the grammar augmented with a new start production S' → S
+#[derive(Clone, Debug)]
+pub struct SlrParseTable {
+    grammar: Grammar,
+    // The productions of the augmented grammar, with the new start production first
+    productions: Vec<Production>,
+    // The grammar without useless symbols, whose productions can be part of a derivation
+    useful_grammar: Grammar,
+    item_sets: Vec<BTreeSet<LrItem>>,
+    // Transitions between item sets on terminals and variables
+    transitions: BTreeMap<(usize, char), usize>,
+    actions: BTreeMap<(usize, char), Vec<SlrAction>>,
+}
+
+impl Grammar {
+    /// Returns whether the grammar's SLR(1) parse table has at most one action in each cell
+    pub fn is_slr1(&self) -> bool {
+        SlrParseTable::new(self).is_some_and(|table| table.conflicts().is_empty())
+    }
+}
+
+impl SlrParseTable {
+    /// Builds the parse table, or returns None if the grammar is not context-free
+    pub fn new(grammar: &Grammar) -> Option<Self> {
+        if !grammar.is_context_free() {
+            return None;
+        }
+
+        let start = grammar.start_symbol.to_string();
+        let mut productions = vec![Production::new(&format!("{}'", start), &start)];
+        productions.extend(grammar.productions.iter().cloned());
+
+        let mut table = Self {
+            grammar: grammar.clone(),
+            productions,
+            useful_grammar: grammar.remove_useless_symbols(),
+            item_sets: Vec::new(),
+            transitions: BTreeMap::new(),
+            actions: BTreeMap::new(),
+        };
+        table.build_item_sets();
+        table.build_actions();
+        Some(table)
+    }
+
+    pub fn grammar(&self) -> &Grammar {
+        &self.grammar
+    }
+
+    /// Returns the productions of the augmented grammar, numbered as in reduce actions
+    pub fn productions(&self) -> &[Production] {
+        &self.productions
+    }
+
+    /// Returns the LR(0) item sets, numbered as the parser's states.
+    /// Productions of useless symbols are left out.
+    pub fn item_sets(&self) -> &[BTreeSet<LrItem>] {
+        &self.item_sets
+    }
+
+    /// Returns an item as its production with a dot in the right side, such as S → a·Sb
+    pub fn item_text(&self, item: LrItem) -> String {
+        let production = &self.productions[item.production];
+        let mut rhs: Vec<char> = production.rhs.chars().collect();
+        rhs.insert(item.dot, ITEM_DOT);
+        Production::new(&production.lhs, &rhs.into_iter().collect::<String>()).to_string()
+    }
+
+    /// Returns the columns of the action table, the terminals followed by the end marker
+    pub fn lookaheads(&self) -> Vec<char> {
+        self.grammar
+            .terminals()
+            .into_iter()
+            .chain(std::iter::once(END_MARKER))
+            .collect()
+    }
+
+    /// Returns the columns of the goto table, with the start symbol first
+    pub fn variables(&self) -> Vec<char> {
+        let start_symbol = self.grammar.start_symbol;
+        std::iter::once(start_symbol)
+            .chain(
+                self.grammar
+                    .variables()
+                    .into_iter()
+                    .filter(|&variable| variable != start_symbol),
+            )
+            .collect()
+    }
+
+    pub fn actions(&self, state: usize, lookahead: char) -> &[SlrAction] {
+        self.actions
+            .get(&(state, lookahead))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn goto(&self, state: usize, variable: char) -> Option<usize> {
+        self.transitions.get(&(state, variable)).copied()
+    }
+
+    /// Returns every conflict, in order of state and lookahead
+    pub fn conflicts(&self) -> Vec<SlrConflict> {
+        let mut conflicts = Vec::new();
+        for (&(state, lookahead), actions) in &self.actions {
+            // Accepting is reducing by the new start production
+            let reduce_count = actions
+                .iter()
+                .filter(|action| matches!(action, SlrAction::Reduce(_) | SlrAction::Accept))
+                .count();
+            let has_shift = actions
+                .iter()
+                .any(|action| matches!(action, SlrAction::Shift(_)));
+            if has_shift && reduce_count > 0 {
+                conflicts.push(SlrConflict::ShiftReduce { state, lookahead });
+            }
+            if reduce_count > 1 {
+                conflicts.push(SlrConflict::ReduceReduce { state, lookahead });
+            }
+        }
+        conflicts
+    }
+
+    /// Returns the DFA of LR(0) item sets, where each state's ID is its item set's number,
+    /// transitions read terminals or variables, and item sets with a complete item are final
+    pub fn item_automaton(&self) -> FiniteAutomaton {
+        let mut fa = FiniteAutomaton::default();
+        for (i, items) in self.item_sets.iter().enumerate() {
+            fa.automaton.try_add_state_with_id(i as u32);
+            let has_complete_item = items.iter().any(|&item| self.is_complete(item));
+            fa.automaton.set_final(i as u32, has_complete_item);
+        }
+        fa.automaton.set_initial(0);
+        for (&(from, symbol), &to) in &self.transitions {
+            fa.automaton.add_transition(FiniteAutomatonTransition::new(
+                from as u32,
+                to as u32,
+                symbol,
+            ));
+        }
+        fa
+    }
+
+    /// Parses a string, returning every step up to accepting or rejecting it,
+    /// or None if the table has conflicts
+    pub fn parse(&self, input: &str) -> Option<Vec<SlrParseStep>> {
+        if !self.conflicts().is_empty() {
+            return None;
+        }
+
+        let input: Vec<char> = input.chars().chain(std::iter::once(END_MARKER)).collect();
+        let mut position = 0;
+        let mut states = vec![0];
+        let mut symbols = Vec::new();
+        let mut steps = Vec::new();
+
+        loop {
+            let state = *states.last().unwrap();
+            let next = input[position];
+            let action = match self.actions(state, next).first() {
+                Some(&SlrAction::Shift(next_state)) => SlrParseAction::Shift(next_state),
+                Some(&SlrAction::Reduce(production)) => {
+                    let production = &self.productions[production];
+                    let popped = production.rhs.chars().count();
+                    let uncovered = states[states.len() - 1 - popped];
+                    let variable = production.lhs.chars().next().unwrap();
+                    match self.goto(uncovered, variable) {
+                        Some(next_state) => SlrParseAction::Reduce(production.clone(), next_state),
+                        None => SlrParseAction::Reject,
+                    }
+                }
+                Some(SlrAction::Accept) => SlrParseAction::Accept,
+                None => SlrParseAction::Reject,
+            };
+
+            // The stack alternates states and the symbols between them
+            let mut stack = states[0].to_string();
+            for (symbol, state) in symbols.iter().zip(&states[1..]) {
+                stack += &format!(" {} {}", symbol, state);
+            }
+            steps.push(SlrParseStep {
+                stack,
+                remaining: input[position..].iter().collect(),
+                action: action.clone(),
+            });
+
+            match action {
+                SlrParseAction::Shift(next_state) => {
+                    states.push(next_state);
+                    symbols.push(next);
+                    position += 1;
+                }
+                SlrParseAction::Reduce(production, next_state) => {
+                    let popped = production.rhs.chars().count();
+                    states.truncate(states.len() - popped);
+                    symbols.truncate(symbols.len() - popped);
+                    states.push(next_state);
+                    symbols.push(production.lhs.chars().next().unwrap());
+                }
+                SlrParseAction::Accept | SlrParseAction::Reject => return Some(steps),
+            }
+        }
+    }
+
+    // Returns the symbol after the item's dot, if it is not at the end
+    fn next_symbol(&self, item: LrItem) -> Option<char> {
+        self.productions[item.production].rhs.chars().nth(item.dot)
+    }
+
+    fn is_complete(&self, item: LrItem) -> bool {
+        self.next_symbol(item).is_none()
+    }
+
+    // Adds the initial items of every variable after a dot, until nothing changes.
+    // Useless productions are left out, as a parse could never complete them.
+    fn closure(&self, mut items: BTreeSet<LrItem>) -> BTreeSet<LrItem> {
+        let mut stack: Vec<LrItem> = items.iter().copied().collect();
+        while let Some(item) = stack.pop() {
+            let variable = match self.next_symbol(item) {
+                Some(symbol) if is_variable(symbol) => symbol,
+                _ => continue,
+            };
+            for (i, production) in self.productions.iter().enumerate().skip(1) {
+                let new_item = LrItem {
+                    production: i,
+                    dot: 0,
+                };
+                if production.single_variable_lhs() == Some(variable)
+                    && self.useful_grammar.productions.contains(production)
+                    && items.insert(new_item)
+                {
+                    stack.push(new_item);
+                }
+            }
+        }
+        items
+    }
+
+    // Discovers every item set reachable from the closure of S' → ·S
+    fn build_item_sets(&mut self) {
+        let initial = self.closure(
+            std::iter::once(LrItem {
+                production: 0,
+                dot: 0,
+            })
+            .collect(),
+        );
+        self.item_sets.push(initial);
+
+        let mut current = 0;
+        while current < self.item_sets.len() {
+            // Advance the dot over each symbol, keeping symbols in order for predictable numbering
+            let mut advanced: BTreeMap<char, BTreeSet<LrItem>> = BTreeMap::new();
+            for &item in &self.item_sets[current] {
+                if let Some(symbol) = self.next_symbol(item) {
+                    advanced.entry(symbol).or_default().insert(LrItem {
+                        production: item.production,
+                        dot: item.dot + 1,
+                    });
+                }
+            }
+
+            for (symbol, kernel) in advanced {
+                let items = self.closure(kernel);
+                let next = match self.item_sets.iter().position(|other| *other == items) {
+                    Some(next) => next,
+                    None => {
+                        self.item_sets.push(items);
+                        self.item_sets.len() - 1
+                    }
+                };
+                self.transitions.insert((current, symbol), next);
+            }
+            current += 1;
+        }
+    }
+
+    // Shifts on transitions over terminals, and reduces complete items on the FOLLOW set
+    // of their left side. Like the item sets, FOLLOW sets leave out useless productions,
+    // which could otherwise make the parser reduce forever.
+    fn build_actions(&mut self) {
+        let follow_sets = self.useful_grammar.follow_sets();
+        let mut actions: BTreeMap<(usize, char), Vec<SlrAction>> = BTreeMap::new();
+
+        for (&(state, symbol), &next) in &self.transitions {
+            if !is_variable(symbol) {
+                actions
+                    .entry((state, symbol))
+                    .or_default()
+                    .push(SlrAction::Shift(next));
+            }
+        }
+
+        for (state, items) in self.item_sets.iter().enumerate() {
+            for &item in items {
+                if !self.is_complete(item) {
+                    continue;
+                }
+                if item.production == 0 {
+                    actions
+                        .entry((state, END_MARKER))
+                        .or_default()
+                        .push(SlrAction::Accept);
+                    continue;
+                }
+
+                let variable = self.productions[item.production]
+                    .single_variable_lhs()
+                    .unwrap();
+                for &lookahead in follow_sets.get(&variable).into_iter().flatten() {
+                    let entry = actions.entry((state, lookahead)).or_default();
+                    let reduce = SlrAction::Reduce(item.production);
+                    if !entry.contains(&reduce) {
+                        entry.push(reduce);
+                    }
+                }
+            }
+        }
+
+        self.actions = actions;
+    }
+}
@@ -0,0 +1,379 @@
+use super::{is_variable, Grammar, Production};
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fmt;
+
+/// The ways a grammar transformation can fail
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TransformError {
+    /// The transformation only applies to context-free grammars
+    NotContextFree,
+    /// The transformation only applies to right-linear grammars
+    NotRightLinear,
+    /// A new variable was needed, but every uppercase letter is already in use
+    OutOfVariables,
+    /// The result would have more productions than is practical
+    TooManyProductions,
+    /// An uppercase letter would have to be a terminal, but is read as a variable
+    VariableAsTerminal(char),
+}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotContextFree => write!(f, "The grammar is not context-free"),
+            Self::NotRightLinear => write!(f, "The grammar is not right-linear"),
+            Self::OutOfVariables => write!(f, "Every variable name is already in use"),
+            Self::TooManyProductions => write!(f, "The result would have too many productions"),
+            Self::VariableAsTerminal(symbol) => write!(
+                f,
+                "The symbol {} would be a terminal, but uppercase letters are variables",
+                symbol
+            ),
+        }
+    }
+}
+
+impl Error for TransformError {}
+
+/// A stage of transforming a grammar into a normal form
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TransformationKind {
+    RemoveEmptyProductions,
+    RemoveUnitProductions,
+    RemoveUselessSymbols,
+    ChomskyNormalForm,
+}
+
+impl fmt::Display for TransformationKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            Self::RemoveEmptyProductions => "Remove λ-productions",
+            Self::RemoveUnitProductions => "Remove unit productions",
+            Self::RemoveUselessSymbols => "Remove useless symbols",
+            Self::ChomskyNormalForm => "Convert to Chomsky normal form",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+/// The grammar resulting from one stage of a transformation
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransformationStep {
+    pub kind: TransformationKind,
+    pub grammar: Grammar,
+}
+
+impl Grammar {
+    /// Returns every intermediate grammar of converting to Chomsky normal form,
+    /// the last of which is in Chomsky normal form and generates the same language
+    pub fn chomsky_normal_form_steps(&self) -> Result<Vec<TransformationStep>, TransformError> {
+        let mut steps = self.simplification_steps()?;
+        let simplified = &steps.last().unwrap().grammar;
+        let grammar = simplified.split_into_chomsky_normal_form()?;
+        steps.push(TransformationStep {
+            kind: TransformationKind::ChomskyNormalForm,
+            grammar,
+        });
+        Ok(steps)
+    }
+
+    pub fn to_chomsky_normal_form(&self) -> Result<Grammar, TransformError> {
+        Ok(self.chomsky_normal_form_steps()?.pop().unwrap().grammar)
+    }
+
+    /// Returns the grammars after removing λ-productions, unit productions and useless symbols, in order
+    pub fn simplification_steps(&self) -> Result<Vec<TransformationStep>, TransformError> {
+        if !self.is_context_free() {
+            return Err(TransformError::NotContextFree);
+        }
+
+        let without_empty = self.remove_empty_productions()?;
+        let without_unit = without_empty.remove_unit_productions();
+        let without_useless = without_unit.remove_useless_symbols();
+        Ok(vec![
+            TransformationStep {
+                kind: TransformationKind::RemoveEmptyProductions,
+                grammar: without_empty,
+            },
+            TransformationStep {
+                kind: TransformationKind::RemoveUnitProductions,
+                grammar: without_unit,
+            },
+            TransformationStep {
+                kind: TransformationKind::RemoveUselessSymbols,
+                grammar: without_useless,
+            },
+        ])
+    }
+
+    /// Returns the variables which derive the empty string
+    pub fn nullable_variables(&self) -> BTreeSet<char> {
+        let mut nullable = BTreeSet::new();
+        loop {
+            let mut changed = false;
+            for production in &self.productions {
+                if let Some(variable) = production.single_variable_lhs() {
+                    if !nullable.contains(&variable)
+                        && production
+                            .rhs
+                            .chars()
+                            .all(|symbol| nullable.contains(&symbol))
+                    {
+                        nullable.insert(variable);
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                return nullable;
+            }
+        }
+    }
+
+    /// Replaces each production with every version of it omitting nullable variables,
+    /// leaving only the start symbol deriving the empty string, if the language contains it.
+    /// A new start symbol is introduced if the old one would derive the empty string
+    /// while appearing on a right side.
+    pub fn remove_empty_productions(&self) -> Result<Grammar, TransformError> {
+        let nullable = self.nullable_variables();
+        let mut grammar = Grammar::new(Vec::new(), self.start_symbol);
+
+        for production in &self.productions {
+            // Build every version of the right side one symbol at a time, keeping or omitting
+            // each nullable variable. Versions which turn out the same are only extended once,
+            // so repeated nullable variables do not multiply the work.
+            let mut right_sides = vec![String::new()];
+            for symbol in production.rhs.chars() {
+                let mut extended: Vec<String> = Vec::new();
+                let kept = right_sides.iter().map(|rhs| format!("{}{}", rhs, symbol));
+                let omitted = right_sides
+                    .iter()
+                    .filter(|_| nullable.contains(&symbol))
+                    .cloned();
+                for version in kept.chain(omitted) {
+                    if !extended.contains(&version) {
+                        extended.push(version);
+                    }
+                }
+                right_sides = extended;
+            }
+
+            for rhs in right_sides {
+                if !rhs.is_empty() {
+                    grammar.add_production(Production::new(&production.lhs, &rhs));
+                }
+            }
+        }
+
+        if nullable.contains(&self.start_symbol) {
+            let start_on_right = grammar
+                .productions
+                .iter()
+                .any(|production| production.rhs.contains(self.start_symbol));
+            if start_on_right {
+                let new_start = self.fresh_variable(&BTreeSet::new())?.to_string();
+                grammar.productions.splice(
+                    0..0,
+                    vec![
+                        Production::new(&new_start, &self.start_symbol.to_string()),
+                        Production::new(&new_start, ""),
+                    ],
+                );
+                grammar.start_symbol = new_start.chars().next().unwrap();
+            } else {
+                grammar.add_production(Production::new(&self.start_symbol.to_string(), ""));
+            }
+        }
+
+        Ok(grammar)
+    }
+
+    /// Replaces productions of the form A → B with A → α for every non-unit production B → α
+    /// of each variable B reachable from A by unit productions
+    pub fn remove_unit_productions(&self) -> Grammar {
+        let unit_target = |production: &Production| {
+            let mut chars = production.rhs.chars();
+            match (chars.next(), chars.next()) {
+                (Some(symbol), None) if is_variable(symbol) => Some(symbol),
+                _ => None,
+            }
+        };
+
+        let mut grammar = Grammar::new(Vec::new(), self.start_symbol);
+        let mut seen = BTreeSet::new();
+        let variables: Vec<char> = self
+            .productions
+            .iter()
+            .filter_map(Production::single_variable_lhs)
+            .filter(|&variable| seen.insert(variable))
+            .collect();
+        for variable in variables {
+            // Find every variable reachable with unit productions
+            let mut reachable = BTreeSet::new();
+            let mut stack = vec![variable];
+            reachable.insert(variable);
+            while let Some(current) = stack.pop() {
+                for production in self.productions_of(current) {
+                    if let Some(target) = unit_target(production) {
+                        if reachable.insert(target) {
+                            stack.push(target);
+                        }
+                    }
+                }
+            }
+
+            // Keep the productions in their original order, for a predictable result
+            for production in &self.productions {
+                let from_reachable = production
+                    .single_variable_lhs()
+                    .is_some_and(|lhs| reachable.contains(&lhs));
+                if from_reachable && unit_target(production).is_none() {
+                    grammar.add_production(Production::new(&variable.to_string(), &production.rhs));
+                }
+            }
+        }
+
+        grammar
+    }
+
+    /// Removes productions involving variables which derive no string of terminals,
+    /// then productions of variables unreachable from the start symbol
+    pub fn remove_useless_symbols(&self) -> Grammar {
+        let mut generating = BTreeSet::new();
+        loop {
+            let mut changed = false;
+            for production in &self.productions {
+                if let Some(variable) = production.single_variable_lhs() {
+                    if !generating.contains(&variable)
+                        && production
+                            .rhs
+                            .chars()
+                            .all(|symbol| !is_variable(symbol) || generating.contains(&symbol))
+                    {
+                        generating.insert(variable);
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let generating_productions: Vec<&Production> = self
+            .productions
+            .iter()
+            .filter(|production| {
+                production
+                    .lhs
+                    .chars()
+                    .chain(production.rhs.chars())
+                    .all(|symbol| !is_variable(symbol) || generating.contains(&symbol))
+            })
+            .collect();
+
+        let mut reachable = BTreeSet::new();
+        let mut stack = vec![self.start_symbol];
+        reachable.insert(self.start_symbol);
+        while let Some(current) = stack.pop() {
+            for production in &generating_productions {
+                if production.single_variable_lhs() == Some(current) {
+                    for symbol in production.rhs.chars().filter(|&symbol| is_variable(symbol)) {
+                        if reachable.insert(symbol) {
+                            stack.push(symbol);
+                        }
+                    }
+                }
+            }
+        }
+
+        Grammar::new(
+            generating_productions
+                .into_iter()
+                .filter(|production| {
+                    production
+                        .single_variable_lhs()
+                        .is_some_and(|lhs| reachable.contains(&lhs))
+                })
+                .cloned()
+                .collect(),
+            self.start_symbol,
+        )
+    }
+
+    /// Converts a grammar without λ-productions (other than from the start symbol)
+    /// or unit productions into Chomsky normal form.
+    /// Terminals in longer right sides are replaced by new variables,
+    /// and right sides longer than two symbols are split into chains of new variables.
+    pub fn split_into_chomsky_normal_form(&self) -> Result<Grammar, TransformError> {
+        let mut grammar = Grammar::new(Vec::new(), self.start_symbol);
+        let mut used: BTreeSet<char> = self.variables();
+        let mut terminal_variables: BTreeMap<char, char> = BTreeMap::new();
+        // New variables for the same sequence of symbols are shared
+        let mut suffix_variables: BTreeMap<String, char> = BTreeMap::new();
+        let mut new_productions = Vec::new();
+
+        for production in &self.productions {
+            let symbols: Vec<char> = production.rhs.chars().collect();
+            if symbols.len() < 2 {
+                grammar.add_production(production.clone());
+                continue;
+            }
+
+            let mut replaced = String::new();
+            for symbol in symbols {
+                if is_variable(symbol) {
+                    replaced.push(symbol);
+                } else if let Some(&variable) = terminal_variables.get(&symbol) {
+                    replaced.push(variable);
+                } else {
+                    let variable = self.fresh_variable(&used)?;
+                    used.insert(variable);
+                    terminal_variables.insert(symbol, variable);
+                    new_productions
+                        .push(Production::new(&variable.to_string(), &symbol.to_string()));
+                    replaced.push(variable);
+                }
+            }
+
+            let mut lhs = production.lhs.clone();
+            let mut rest: Vec<char> = replaced.chars().collect();
+            while rest.len() > 2 {
+                let suffix: String = rest[1..].iter().collect();
+                let (variable, is_new) = match suffix_variables.get(&suffix) {
+                    Some(&variable) => (variable, false),
+                    None => {
+                        let variable = self.fresh_variable(&used)?;
+                        used.insert(variable);
+                        suffix_variables.insert(suffix, variable);
+                        (variable, true)
+                    }
+                };
+                grammar.add_production(Production::new(&lhs, &format!("{}{}", rest[0], variable)));
+                if !is_new {
+                    break;
+                }
+                lhs = variable.to_string();
+                rest.remove(0);
+            }
+            if rest.len() == 2 {
+                grammar.add_production(Production::new(&lhs, &rest.iter().collect::<String>()));
+            }
+        }
+
+        for production in new_productions {
+            grammar.add_production(production);
+        }
+        Ok(grammar)
+    }
+
+    /// Returns the first uppercase letter which is neither a variable of the grammar nor already used
+    pub(super) fn fresh_variable(&self, used: &BTreeSet<char>) -> Result<char, TransformError> {
+        let variables = self.variables();
+        ('A'..='Z')
+            .find(|variable| !variables.contains(variable) && !used.contains(variable))
+            .ok_or(TransformError::OutOfVariables)
+    }
+}
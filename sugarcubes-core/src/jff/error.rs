@@ -0,0 +1,89 @@
+use std::error::Error;
+use std::fmt;
+
+/// The problems which can be found in a JFF file.
+///
+/// Some make the whole file unreadable, while others only cause a single state,
+/// transition or production to be skipped, and are reported as warnings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JffError {
+    /// The file is not well-formed XML, with the position counted from line 1, column 1
+    Syntax {
+        line: u64,
+        column: u64,
+        message: String,
+    },
+    /// The file is XML which cannot be processed, such as with processing instructions
+    UnsupportedXml,
+    /// A required element is missing from its parent
+    MissingElement {
+        name: &'static str,
+        parent: &'static str,
+    },
+    /// A required attribute is missing from an element
+    MissingAttribute {
+        name: &'static str,
+        element: &'static str,
+    },
+    /// An element or attribute holds text which cannot be read as what it describes
+    InvalidValue { value: String, kind: &'static str },
+    /// The file holds a type of document which is not supported
+    UnsupportedType(String),
+    /// A transition goes from or to a state which does not exist
+    DanglingTransition { from: u32, to: u32 },
+    /// More than one state has the same ID
+    DuplicateState(u32),
+    /// A transition reads more than one symbol, which the automaton does not allow
+    MultiCharacterRead { from: u32, to: u32, read: String },
+    /// A transition reads nothing, which the automaton does not allow
+    EmptyRead { from: u32, to: u32 },
+}
+
+impl fmt::Display for JffError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Syntax {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Invalid XML at line {}, column {}: {}",
+                line, column, message
+            ),
+            Self::UnsupportedXml => write!(f, "The file contains XML which cannot be processed"),
+            Self::MissingElement { name, parent } => {
+                write!(f, "<{}> has no <{}> element", parent, name)
+            }
+            Self::MissingAttribute { name, element } => {
+                write!(
+                    f,
+                    "A <{}> element is missing its {} attribute",
+                    element, name
+                )
+            }
+            Self::InvalidValue { value, kind } => {
+                write!(f, "\"{}\" is not a valid {}", value, kind)
+            }
+            Self::UnsupportedType(name) => write!(f, "The type \"{}\" is not supported", name),
+            Self::DanglingTransition { from, to } => write!(
+                f,
+                "The transition from {} to {} has an endpoint which is not a state",
+                from, to
+            ),
+            Self::DuplicateState(id) => write!(f, "More than one state has the ID {}", id),
+            Self::MultiCharacterRead { from, to, read } => write!(
+                f,
+                "The transition from {} to {} reads \"{}\", but may only read one symbol",
+                from, to, read
+            ),
+            Self::EmptyRead { from, to } => write!(
+                f,
+                "The transition from {} to {} reads nothing, but must read a symbol",
+                from, to
+            ),
+        }
+    }
+}
+
+impl Error for JffError {}
@@ -0,0 +1,44 @@
+//! Documents in the XML format of JFLAP's .jff files
+
+mod error;
+mod read;
+
+pub use error::JffError;
+pub use read::read_jff;
+
+use crate::automata::{
+    finite_automaton::FiniteAutomaton, mealy_machine::MealyMachine, moore_machine::MooreMachine,
+    pushdown_automaton::PushdownAutomaton, turing_machine::TuringMachine,
+};
+use crate::grammar::Grammar;
+
+/// The name and position of a state, which only matter for displaying it,
+/// with the position in JFLAP's coordinates
+#[derive(Clone, Debug, PartialEq)]
+pub struct JffState {
+    pub id: u32,
+    pub name: String,
+    pub x: f32,
+    pub y: f32,
+}
+
+/// An automaton of any type a JFF file can hold
+pub enum JffAutomaton {
+    FiniteAutomaton(FiniteAutomaton),
+    TuringMachine(TuringMachine),
+    MealyMachine(MealyMachine),
+    MooreMachine(MooreMachine),
+    PushdownAutomaton(PushdownAutomaton),
+}
+
+/// The contents of a JFF file
+#[allow(clippy::large_enum_variant)]
+pub enum JffDocument {
+    Automaton {
+        automaton: JffAutomaton,
+        states: Vec<JffState>,
+    },
+    /// The text of a regular expression
+    Regex(String),
+    Grammar(Grammar),
+}
@@ -0,0 +1,328 @@
+use super::{JffAutomaton, JffDocument, JffError, JffState};
+use crate::automata::{
+    finite_automaton::{FiniteAutomaton, FiniteAutomatonTransition},
+    mealy_machine::{MealyMachine, MealyMachineTransition},
+    moore_machine::MooreMachine,
+    pushdown_automaton::{PushdownAutomaton, PushdownAutomatonTransition},
+    turing_machine::{Direction, TapeAction, TuringMachine, TuringMachineTransition},
+    Automaton, Transition, EMPTY_STRING,
+};
+use crate::grammar::{Grammar, Production};
+
+use std::collections::BTreeSet;
+use std::str::FromStr;
+use xml::common::Position;
+use xmltree::{Element, ParseError, XMLNode};
+
+/// Reads the contents of a JFF file, along with warnings about anything skipped as malformed
+pub fn read_jff(content: &str) -> Result<(JffDocument, Vec<JffError>), JffError> {
+    let structure = Element::parse(content.as_bytes()).map_err(|error| match error {
+        ParseError::MalformedXml(error) => JffError::Syntax {
+            line: error.position().row + 1,
+            column: error.position().column + 1,
+            message: error.msg().to_string(),
+        },
+        ParseError::CannotParse => JffError::UnsupportedXml,
+    })?;
+
+    let mut warnings = Vec::new();
+    let document = match child_text(&structure, "type", "structure")?.as_str() {
+        "re" => JffDocument::Regex(child_text(&structure, "expression", "structure")?),
+        "grammar" => JffDocument::Grammar(read_grammar(&structure, &mut warnings)),
+        _ => read_automaton(&structure, &mut warnings)?,
+    };
+    Ok((document, warnings))
+}
+
+// A state's element, before it is added to the automaton
+struct StateElement {
+    state: JffState,
+    is_initial: bool,
+    is_final: bool,
+    // Only Turing machines have reject states
+    is_reject: bool,
+    output: Option<String>,
+}
+
+fn read_automaton(
+    structure: &Element,
+    warnings: &mut Vec<JffError>,
+) -> Result<JffDocument, JffError> {
+    let mut automaton = match child_text(structure, "type", "structure")?.as_str() {
+        "fa" => JffAutomaton::FiniteAutomaton(FiniteAutomaton::default()),
+        "turing" => {
+            // Multi-tape machines list their number of tapes, which is otherwise one
+            let tape_count = match structure.get_child("tapes") {
+                Some(_) => parse(&child_text(structure, "tapes", "structure")?, "tape count")?,
+                None => 1,
+            };
+            JffAutomaton::TuringMachine(TuringMachine::with_tapes(tape_count))
+        }
+        "mealy" => JffAutomaton::MealyMachine(MealyMachine::default()),
+        "moore" => JffAutomaton::MooreMachine(MooreMachine::default()),
+        "pda" => JffAutomaton::PushdownAutomaton(PushdownAutomaton::default()),
+        other => return Err(JffError::UnsupportedType(other.to_string())),
+    };
+    let automaton_element = structure
+        .get_child("automaton")
+        .ok_or(JffError::MissingElement {
+            name: "automaton",
+            parent: "structure",
+        })?;
+    let elements = |name: &'static str| {
+        automaton_element
+            .children
+            .iter()
+            .filter_map(move |child| match child {
+                XMLNode::Element(element) if element.name == name => Some(element),
+                _ => None,
+            })
+    };
+
+    // First, read and add all the states, skipping any which are malformed or repeat an ID
+    let mut ids = BTreeSet::new();
+    let mut state_elements = Vec::new();
+    for element in elements("state") {
+        match read_state(element) {
+            Ok(state_element) if !ids.insert(state_element.state.id) => {
+                warnings.push(JffError::DuplicateState(state_element.state.id));
+            }
+            Ok(state_element) => state_elements.push(state_element),
+            Err(error) => warnings.push(error),
+        }
+    }
+    match &mut automaton {
+        JffAutomaton::FiniteAutomaton(fa) => add_states(&mut fa.automaton, &state_elements),
+        JffAutomaton::TuringMachine(tm) => {
+            add_states(&mut tm.automaton, &state_elements);
+            for state_element in &state_elements {
+                if state_element.is_reject {
+                    tm.set_reject(state_element.state.id, true);
+                }
+            }
+        }
+        JffAutomaton::MealyMachine(mealy) => add_states(&mut mealy.automaton, &state_elements),
+        JffAutomaton::MooreMachine(moore) => {
+            add_states(&mut moore.automaton, &state_elements);
+            for state_element in &state_elements {
+                if let Some(output) = &state_element.output {
+                    moore.set_output(state_element.state.id, output);
+                }
+            }
+        }
+        JffAutomaton::PushdownAutomaton(pda) => add_states(&mut pda.automaton, &state_elements),
+    }
+
+    // After reading all the states, take a second pass to read and add all the transitions
+    for element in elements("transition") {
+        if let Err(error) = read_transition(&mut automaton, element, &ids) {
+            warnings.push(error);
+        }
+    }
+
+    Ok(JffDocument::Automaton {
+        automaton,
+        states: state_elements
+            .into_iter()
+            .map(|state_element| state_element.state)
+            .collect(),
+    })
+}
+
+fn read_state(element: &Element) -> Result<StateElement, JffError> {
+    let attribute = |name: &'static str| {
+        element
+            .attributes
+            .get(name)
+            .ok_or(JffError::MissingAttribute {
+                name,
+                element: "state",
+            })
+    };
+    Ok(StateElement {
+        state: JffState {
+            id: parse(attribute("id")?, "state ID")?,
+            name: attribute("name")?.clone(),
+            x: parse(&child_text(element, "x", "state")?, "coordinate")?,
+            y: parse(&child_text(element, "y", "state")?, "coordinate")?,
+        },
+        is_initial: element.get_child("initial").is_some(),
+        is_final: element.get_child("final").is_some(),
+        is_reject: element.get_child("reject").is_some(),
+        output: element
+            .get_child("output")
+            .map(|output| output.get_text().unwrap_or_default().into_owned()),
+    })
+}
+
+fn add_states<T: Transition>(automaton: &mut Automaton<T>, state_elements: &[StateElement]) {
+    for state_element in state_elements {
+        let id = state_element.state.id;
+        automaton.try_add_state_with_id(id);
+        if state_element.is_initial {
+            automaton.set_initial(id);
+        }
+        if state_element.is_final {
+            automaton.set_final(id, true);
+        }
+    }
+}
+
+// Add a transition to the automaton, given the IDs of the states which were read
+fn read_transition(
+    automaton: &mut JffAutomaton,
+    element: &Element,
+    ids: &BTreeSet<u32>,
+) -> Result<(), JffError> {
+    let from: u32 = parse(&child_text(element, "from", "transition")?, "state ID")?;
+    let to: u32 = parse(&child_text(element, "to", "transition")?, "state ID")?;
+    if !ids.contains(&from) || !ids.contains(&to) {
+        return Err(JffError::DanglingTransition { from, to });
+    }
+    let read = || {
+        single_symbol(child_text(element, "read", "transition")?, from, to)?
+            .ok_or(JffError::EmptyRead { from, to })
+    };
+
+    match automaton {
+        // Finite automata may read any string, with JFLAP leaving it empty for the empty string
+        JffAutomaton::FiniteAutomaton(fa) => {
+            let read = child_text(element, "read", "transition")?;
+            fa.automaton
+                .add_transition(FiniteAutomatonTransition::with_read(from, to, &read));
+        }
+        JffAutomaton::MooreMachine(moore) => {
+            moore
+                .automaton
+                .add_transition(FiniteAutomatonTransition::new(from, to, read()?));
+        }
+        JffAutomaton::TuringMachine(tm) => {
+            let actions = (1..=tm.tape_count)
+                .map(|tape| read_tape_action(element, tape, tm.blank_symbol, from, to))
+                .collect::<Result<Vec<TapeAction>, JffError>>()?;
+            tm.automaton
+                .add_transition(TuringMachineTransition::with_actions(from, to, actions));
+        }
+        JffAutomaton::MealyMachine(mealy) => {
+            let output = child_text(element, "transout", "transition").unwrap_or_default();
+            mealy
+                .automaton
+                .add_transition(MealyMachineTransition::new(from, to, read()?, &output));
+        }
+        // JFLAP leaves each part empty for the empty string
+        JffAutomaton::PushdownAutomaton(pda) => {
+            let read = single_symbol(child_text(element, "read", "transition")?, from, to)?
+                .unwrap_or(EMPTY_STRING);
+            pda.automaton
+                .add_transition(PushdownAutomatonTransition::new(
+                    from,
+                    to,
+                    read,
+                    &child_text(element, "pop", "transition")?,
+                    &child_text(element, "push", "transition")?,
+                ));
+        }
+    }
+    Ok(())
+}
+
+// JFLAP has no start symbol element, instead starting from the left side of the first production
+fn read_grammar(structure: &Element, warnings: &mut Vec<JffError>) -> Grammar {
+    let mut grammar = Grammar::default();
+    for child in &structure.children {
+        if let XMLNode::Element(element) = child {
+            if element.name.as_str() == "production" {
+                let production = child_text(element, "left", "production").and_then(|lhs| {
+                    let rhs = child_text(element, "right", "production")?;
+                    Ok(Production::new(&lhs, &rhs))
+                });
+                match production {
+                    Ok(production) => grammar.add_production(production),
+                    Err(error) => warnings.push(error),
+                }
+            }
+        }
+    }
+
+    if let Some(start_symbol) = grammar
+        .productions
+        .first()
+        .and_then(Production::single_variable_lhs)
+    {
+        grammar.start_symbol = start_symbol;
+    }
+    grammar
+}
+
+// Read the action on the given tape (numbered from 1) of a Turing machine transition.
+// Single-tape machines may omit the tape attributes, and an empty symbol is a blank.
+fn read_tape_action(
+    transition: &Element,
+    tape: usize,
+    blank_symbol: char,
+    from: u32,
+    to: u32,
+) -> Result<TapeAction, JffError> {
+    let child = |name: &'static str| {
+        transition
+            .children
+            .iter()
+            .find_map(|child| match child {
+                XMLNode::Element(element)
+                    if element.name == name
+                        && element
+                            .attributes
+                            .get("tape")
+                            .map_or(tape == 1, |attribute| *attribute == tape.to_string()) =>
+                {
+                    Some(element.get_text().unwrap_or_default().into_owned())
+                }
+                _ => None,
+            })
+            .ok_or(JffError::MissingElement {
+                name,
+                parent: "transition",
+            })
+    };
+
+    let read = single_symbol(child("read")?, from, to)?.unwrap_or(blank_symbol);
+    let write = child("write")?.chars().next().unwrap_or(blank_symbol);
+    let movement = child("move")?;
+    let direction = movement
+        .chars()
+        .next()
+        .and_then(Direction::from_char)
+        .ok_or(JffError::InvalidValue {
+            value: movement,
+            kind: "direction",
+        })?;
+    Ok(TapeAction::new(read, write, direction))
+}
+
+// The text of a required child element, which is empty if the element has none
+fn child_text(
+    element: &Element,
+    name: &'static str,
+    parent: &'static str,
+) -> Result<String, JffError> {
+    element
+        .get_child(name)
+        .map(|child| child.get_text().unwrap_or_default().into_owned())
+        .ok_or(JffError::MissingElement { name, parent })
+}
+
+fn parse<T: FromStr>(text: &str, kind: &'static str) -> Result<T, JffError> {
+    text.trim().parse().map_err(|_| JffError::InvalidValue {
+        value: text.to_string(),
+        kind,
+    })
+}
+
+// The symbol read by a transition, if any, which may not be more than one character
+fn single_symbol(read: String, from: u32, to: u32) -> Result<Option<char>, JffError> {
+    let mut chars = read.chars();
+    match (chars.next(), chars.next()) {
+        (symbol, None) => Ok(symbol),
+        _ => Err(JffError::MultiCharacterRead { from, to, read }),
+    }
+}
@@ -1 +1,4 @@
 pub mod automata;
+pub mod grammar;
+pub mod jff;
+pub mod regex;
@@ -0,0 +1,97 @@
+pub mod parser;
+pub mod state_elimination;
+mod thompson;
+
+pub use parser::{ParseError, ParseErrorKind};
+pub use state_elimination::{EliminationOrder, GeneralizedAutomaton};
+
+use std::fmt;
+
+/// The symbol used for the empty string in JFLAP-compatible regular expressions
+pub const EMPTY_STRING_SYMBOL: char = '!';
+/// The symbol used for the empty set, which has no JFLAP equivalent
+pub const EMPTY_SET_SYMBOL: char = '∅';
+
+pub const UNION_SYMBOL: char = '+';
+pub const STAR_SYMBOL: char = '*';
+
+/// The syntax tree of a regular expression
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Regex {
+    EmptySet,
+    EmptyString,
+    Symbol(char),
+    Union(Box<Regex>, Box<Regex>),
+    Concatenation(Box<Regex>, Box<Regex>),
+    Star(Box<Regex>),
+}
+
+// Binding strength of each kind of expression, from loosest to tightest
+const UNION_PRECEDENCE: u8 = 0;
+const CONCATENATION_PRECEDENCE: u8 = 1;
+const STAR_PRECEDENCE: u8 = 2;
+
+impl Regex {
+    pub fn union(left: Regex, right: Regex) -> Self {
+        Self::Union(Box::new(left), Box::new(right))
+    }
+
+    pub fn concatenation(left: Regex, right: Regex) -> Self {
+        Self::Concatenation(Box::new(left), Box::new(right))
+    }
+
+    pub fn star(inner: Regex) -> Self {
+        Self::Star(Box::new(inner))
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::Union(_, _) => UNION_PRECEDENCE,
+            Self::Concatenation(_, _) => CONCATENATION_PRECEDENCE,
+            Self::Star(_) => STAR_PRECEDENCE,
+            // Atoms never need parentheses
+            _ => u8::MAX,
+        }
+    }
+
+    // Write the expression, wrapping it in parentheses if it binds more loosely than required.
+    // Union and concatenation are associative, so their operands only need to bind
+    // as tightly as the operator itself.
+    fn fmt_with_precedence(&self, f: &mut fmt::Formatter, precedence: u8) -> fmt::Result {
+        let parenthesize = self.precedence() < precedence;
+        if parenthesize {
+            write!(f, "(")?;
+        }
+
+        match self {
+            Self::EmptySet => write!(f, "{}", EMPTY_SET_SYMBOL)?,
+            Self::EmptyString => write!(f, "{}", EMPTY_STRING_SYMBOL)?,
+            Self::Symbol(symbol) => write!(f, "{}", symbol)?,
+            Self::Union(left, right) => {
+                left.fmt_with_precedence(f, UNION_PRECEDENCE)?;
+                write!(f, "{}", UNION_SYMBOL)?;
+                right.fmt_with_precedence(f, UNION_PRECEDENCE)?;
+            }
+            Self::Concatenation(left, right) => {
+                left.fmt_with_precedence(f, CONCATENATION_PRECEDENCE)?;
+                right.fmt_with_precedence(f, CONCATENATION_PRECEDENCE)?;
+            }
+            Self::Star(inner) => {
+                inner.fmt_with_precedence(f, STAR_PRECEDENCE)?;
+                write!(f, "{}", STAR_SYMBOL)?;
+            }
+        }
+
+        if parenthesize {
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints the expression in JFLAP syntax, with as few parentheses as possible
+impl fmt::Display for Regex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_precedence(f, UNION_PRECEDENCE)
+    }
+}
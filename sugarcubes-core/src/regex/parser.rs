@@ -0,0 +1,153 @@
+use super::{Regex, EMPTY_SET_SYMBOL, EMPTY_STRING_SYMBOL, STAR_SYMBOL, UNION_SYMBOL};
+use crate::automata::EMPTY_STRING;
+
+use std::{fmt, iter::Peekable, str::FromStr};
+
+/// The kinds of mistakes that can appear in a regular expression
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The whole expression is blank
+    EmptyExpression,
+    /// An operand was expected, but an operator, a closing parenthesis or the end was found
+    ExpectedOperand,
+    /// An opening parenthesis is never closed
+    UnmatchedOpenParenthesis,
+    /// A closing parenthesis has no matching opening parenthesis
+    UnmatchedCloseParenthesis,
+}
+
+/// A parse failure, located at a character position in the input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    /// The index of the offending character, counted in characters rather than bytes
+    pub position: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self.kind {
+            ParseErrorKind::EmptyExpression => "the expression is empty",
+            ParseErrorKind::ExpectedOperand => "expected a symbol or \"(\"",
+            ParseErrorKind::UnmatchedOpenParenthesis => "\"(\" is never closed",
+            ParseErrorKind::UnmatchedCloseParenthesis => "\")\" has no matching \"(\"",
+        };
+        write!(f, "{} at position {}", message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl FromStr for Regex {
+    type Err = ParseError;
+
+    /// Parses JFLAP syntax: `+` for union, juxtaposition for concatenation, `*` for Kleene star,
+    /// and `!` (or `ε`/`λ`) for the empty string. Whitespace is ignored.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            chars: input
+                .chars()
+                .enumerate()
+                .filter(|(_, c)| !c.is_whitespace())
+                .peekable(),
+            length: input.chars().count(),
+        };
+
+        if parser.peek().is_none() {
+            return Err(ParseError {
+                kind: ParseErrorKind::EmptyExpression,
+                position: 0,
+            });
+        }
+
+        let regex = parser.union()?;
+        match parser.peek() {
+            None => Ok(regex),
+            // The only way an expression can stop early is at an unopened parenthesis
+            Some((position, _)) => Err(ParseError {
+                kind: ParseErrorKind::UnmatchedCloseParenthesis,
+                position,
+            }),
+        }
+    }
+}
+
+// A recursive descent parser over the non-whitespace characters and their positions
+struct Parser<I: Iterator<Item = (usize, char)>> {
+    chars: Peekable<I>,
+    length: usize,
+}
+
+impl<I: Iterator<Item = (usize, char)>> Parser<I> {
+    fn peek(&mut self) -> Option<(usize, char)> {
+        self.chars.peek().cloned()
+    }
+
+    fn union(&mut self) -> Result<Regex, ParseError> {
+        let mut regex = self.concatenation()?;
+        while let Some((_, UNION_SYMBOL)) = self.peek() {
+            self.chars.next();
+            regex = Regex::union(regex, self.concatenation()?);
+        }
+        Ok(regex)
+    }
+
+    fn concatenation(&mut self) -> Result<Regex, ParseError> {
+        let mut regex = self.star()?;
+        while let Some((_, c)) = self.peek() {
+            if c == UNION_SYMBOL || c == ')' {
+                break;
+            }
+            regex = Regex::concatenation(regex, self.star()?);
+        }
+        Ok(regex)
+    }
+
+    fn star(&mut self) -> Result<Regex, ParseError> {
+        let mut regex = self.atom()?;
+        while let Some((_, STAR_SYMBOL)) = self.peek() {
+            self.chars.next();
+            regex = Regex::star(regex);
+        }
+        Ok(regex)
+    }
+
+    fn atom(&mut self) -> Result<Regex, ParseError> {
+        let (position, c) = match self.chars.next() {
+            Some(next) => next,
+            None => {
+                return Err(ParseError {
+                    kind: ParseErrorKind::ExpectedOperand,
+                    position: self.length,
+                })
+            }
+        };
+
+        match c {
+            '(' => {
+                // An expression ending right after "(" is missing its ")" rather than an operand
+                if self.peek().is_none() {
+                    return Err(ParseError {
+                        kind: ParseErrorKind::UnmatchedOpenParenthesis,
+                        position,
+                    });
+                }
+                let regex = self.union()?;
+                match self.chars.next() {
+                    Some((_, ')')) => Ok(regex),
+                    _ => Err(ParseError {
+                        kind: ParseErrorKind::UnmatchedOpenParenthesis,
+                        position,
+                    }),
+                }
+            }
+            UNION_SYMBOL | STAR_SYMBOL | ')' => Err(ParseError {
+                kind: ParseErrorKind::ExpectedOperand,
+                position,
+            }),
+            EMPTY_STRING_SYMBOL | EMPTY_STRING | 'λ' => Ok(Regex::EmptyString),
+            EMPTY_SET_SYMBOL => Ok(Regex::EmptySet),
+            symbol => Ok(Regex::Symbol(symbol)),
+        }
+    }
+}
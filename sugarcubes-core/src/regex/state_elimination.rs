@@ -0,0 +1,214 @@
+use super::Regex;
+use crate::automata::{finite_automaton::FiniteAutomaton, Transition};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The order in which states are eliminated when converting an automaton to a regular expression
+#[derive(Clone, Debug, PartialEq)]
+pub enum EliminationOrder {
+    /// Eliminate states in order of increasing ID
+    Ascending,
+    /// Repeatedly eliminate the state with the fewest paths through it,
+    /// which tends to produce shorter expressions.
+    /// Ties are broken by the smallest ID, so the order is still deterministic.
+    FewestPaths,
+    /// Eliminate states in the given order, followed by any states left out of it in ascending order
+    Custom(Vec<u32>),
+}
+
+/// A generalized NFA, whose transitions are labelled with regular expressions.
+///
+/// It has a dedicated initial state with no incoming transitions
+/// and a dedicated final state with no outgoing transitions,
+/// so that eliminating every other state leaves a single transition between them.
+pub struct GeneralizedAutomaton {
+    states: BTreeSet<u32>,
+    initial: u32,
+    accept: u32,
+    // At most one label for each pair of states
+    labels: BTreeMap<(u32, u32), Regex>,
+}
+
+impl GeneralizedAutomaton {
+    pub fn new(fa: &FiniteAutomaton) -> Self {
+        let next_id = fa
+            .automaton
+            .states_iter()
+            .last()
+            .map_or(0, |&last| last + 1);
+        let (initial, accept) = (next_id, next_id + 1);
+
+        let mut gnfa = Self {
+            states: fa.automaton.states_iter().cloned().collect(),
+            initial,
+            accept,
+            labels: BTreeMap::new(),
+        };
+
+        if let Some(fa_initial) = fa.automaton.initial() {
+            gnfa.add_label(initial, fa_initial, Regex::EmptyString);
+        }
+        for &state in fa.automaton.states_iter() {
+            if fa.automaton.is_final(state) {
+                gnfa.add_label(state, accept, Regex::EmptyString);
+            }
+        }
+        for transition in fa.automaton.transitions() {
+            // A transition reading several symbols is labeled by their concatenation
+            let label = transition
+                .read()
+                .chars()
+                .map(Regex::Symbol)
+                .reduce(Regex::concatenation)
+                .unwrap_or(Regex::EmptyString);
+            gnfa.add_label(transition.from(), transition.to(), label);
+        }
+
+        gnfa
+    }
+
+    pub fn initial(&self) -> u32 {
+        self.initial
+    }
+
+    pub fn accept(&self) -> u32 {
+        self.accept
+    }
+
+    /// Returns the states that have not been eliminated yet, excluding the initial and final states
+    pub fn remaining_states(&self) -> &BTreeSet<u32> {
+        &self.states
+    }
+
+    /// Returns every label, keyed by the states it goes from and to
+    pub fn labels(&self) -> &BTreeMap<(u32, u32), Regex> {
+        &self.labels
+    }
+
+    pub fn label(&self, from: u32, to: u32) -> Option<&Regex> {
+        self.labels.get(&(from, to))
+    }
+
+    /// Returns whether every state has been eliminated
+    pub fn is_complete(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Removes a state, replacing each path through it with a direct transition
+    pub fn eliminate(&mut self, state: u32) {
+        if !self.states.remove(&state) {
+            return;
+        }
+
+        let self_loop = self.labels.remove(&(state, state)).map(star);
+        let incoming = self.take_labels(|_, to| to == state);
+        let outgoing = self.take_labels(|from, _| from == state);
+
+        for ((from, _), incoming_label) in &incoming {
+            for ((_, to), outgoing_label) in &outgoing {
+                let mut path = incoming_label.clone();
+                if let Some(self_loop) = &self_loop {
+                    path = concatenation(path, self_loop.clone());
+                }
+                path = concatenation(path, outgoing_label.clone());
+                self.add_label(*from, *to, path);
+            }
+        }
+    }
+
+    /// Returns the next state to eliminate according to an ordering, if any remain
+    pub fn next_state(&self, order: &EliminationOrder) -> Option<u32> {
+        match order {
+            EliminationOrder::Ascending => self.states.iter().next().cloned(),
+            EliminationOrder::FewestPaths => self.states.iter().cloned().min_by_key(|&state| {
+                let incoming = self
+                    .labels
+                    .keys()
+                    .filter(|&&(from, to)| to == state && from != state);
+                let outgoing = self
+                    .labels
+                    .keys()
+                    .filter(|&&(from, to)| from == state && to != state);
+                incoming.count() * outgoing.count()
+            }),
+            EliminationOrder::Custom(order) => order
+                .iter()
+                .find(|state| self.states.contains(state))
+                .or_else(|| self.states.iter().next())
+                .cloned(),
+        }
+    }
+
+    /// Returns the expression between the initial and final states,
+    /// or None if some states have not been eliminated yet
+    pub fn to_regex(&self) -> Option<Regex> {
+        if self.is_complete() {
+            Some(
+                self.label(self.initial, self.accept)
+                    .cloned()
+                    .unwrap_or(Regex::EmptySet),
+            )
+        } else {
+            None
+        }
+    }
+
+    // Add a label to a pair of states, in union with any existing label
+    fn add_label(&mut self, from: u32, to: u32, label: Regex) {
+        let label = match self.labels.remove(&(from, to)) {
+            Some(existing) => union(existing, label),
+            None => label,
+        };
+        self.labels.insert((from, to), label);
+    }
+
+    // Remove and return the labels whose states match a predicate
+    fn take_labels<F: Fn(u32, u32) -> bool>(&mut self, predicate: F) -> Vec<((u32, u32), Regex)> {
+        let keys: Vec<(u32, u32)> = self
+            .labels
+            .keys()
+            .cloned()
+            .filter(|&(from, to)| predicate(from, to))
+            .collect();
+        keys.into_iter()
+            .map(|key| (key, self.labels.remove(&key).unwrap()))
+            .collect()
+    }
+}
+
+impl FiniteAutomaton {
+    /// Converts the automaton into an equivalent regular expression by state elimination
+    pub fn to_regex(&self, order: &EliminationOrder) -> Regex {
+        let mut gnfa = GeneralizedAutomaton::new(self);
+        while let Some(state) = gnfa.next_state(order) {
+            gnfa.eliminate(state);
+        }
+        gnfa.to_regex().unwrap()
+    }
+}
+
+// The following build expressions while applying identities that keep them short
+
+fn union(left: Regex, right: Regex) -> Regex {
+    match (left, right) {
+        (Regex::EmptySet, other) | (other, Regex::EmptySet) => other,
+        (left, right) if left == right => left,
+        (left, right) => Regex::union(left, right),
+    }
+}
+
+fn concatenation(left: Regex, right: Regex) -> Regex {
+    match (left, right) {
+        (Regex::EmptySet, _) | (_, Regex::EmptySet) => Regex::EmptySet,
+        (Regex::EmptyString, other) | (other, Regex::EmptyString) => other,
+        (left, right) => Regex::concatenation(left, right),
+    }
+}
+
+fn star(inner: Regex) -> Regex {
+    match inner {
+        Regex::EmptySet | Regex::EmptyString => Regex::EmptyString,
+        Regex::Star(_) => inner,
+        inner => Regex::star(inner),
+    }
+}
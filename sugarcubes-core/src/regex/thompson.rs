@@ -0,0 +1,70 @@
+use super::Regex;
+use crate::automata::{
+    finite_automaton::{FiniteAutomaton, FiniteAutomatonTransition},
+    EMPTY_STRING,
+};
+
+impl Regex {
+    /// Builds an equivalent ε-NFA using Thompson's construction.
+    ///
+    /// The result has a single initial state and a single final state,
+    /// and every subexpression contributes its own pair of start and accept states.
+    pub fn to_finite_automaton(&self) -> FiniteAutomaton {
+        let mut fa = FiniteAutomaton::default();
+        let (start, accept) = self.add_fragment(&mut fa);
+        fa.automaton.set_initial(start);
+        fa.automaton.set_final(accept, true);
+        fa
+    }
+
+    // Add the states and transitions recognizing this expression,
+    // returning the fragment's start and accept states
+    fn add_fragment(&self, fa: &mut FiniteAutomaton) -> (u32, u32) {
+        let empty = |fa: &mut FiniteAutomaton, from, to| {
+            fa.automaton
+                .add_transition(FiniteAutomatonTransition::new(from, to, EMPTY_STRING))
+        };
+
+        match self {
+            Self::EmptySet => (fa.automaton.add_new_state(), fa.automaton.add_new_state()),
+            Self::EmptyString => {
+                let (start, accept) = (fa.automaton.add_new_state(), fa.automaton.add_new_state());
+                empty(fa, start, accept);
+                (start, accept)
+            }
+            Self::Symbol(symbol) => {
+                let (start, accept) = (fa.automaton.add_new_state(), fa.automaton.add_new_state());
+                fa.automaton
+                    .add_transition(FiniteAutomatonTransition::new(start, accept, *symbol));
+                (start, accept)
+            }
+            Self::Union(left, right) => {
+                let start = fa.automaton.add_new_state();
+                let (left_start, left_accept) = left.add_fragment(fa);
+                let (right_start, right_accept) = right.add_fragment(fa);
+                let accept = fa.automaton.add_new_state();
+                empty(fa, start, left_start);
+                empty(fa, start, right_start);
+                empty(fa, left_accept, accept);
+                empty(fa, right_accept, accept);
+                (start, accept)
+            }
+            Self::Concatenation(left, right) => {
+                let (left_start, left_accept) = left.add_fragment(fa);
+                let (right_start, right_accept) = right.add_fragment(fa);
+                empty(fa, left_accept, right_start);
+                (left_start, right_accept)
+            }
+            Self::Star(inner) => {
+                let start = fa.automaton.add_new_state();
+                let (inner_start, inner_accept) = inner.add_fragment(fa);
+                let accept = fa.automaton.add_new_state();
+                empty(fa, start, inner_start);
+                empty(fa, start, accept);
+                empty(fa, inner_accept, inner_start);
+                empty(fa, inner_accept, accept);
+                (start, accept)
+            }
+        }
+    }
+}
@@ -1,6 +1,4 @@
-use crate::states::*;
-
-use sugarcubes_core::automata::finite_automaton::{FiniteAutomaton, FiniteAutomatonTransition};
+use crate::{model::*, states::*};
 
 use macroquad::prelude::Vec2;
 
@@ -12,72 +10,87 @@ pub enum Command {
     RemoveInitial(u32),
     // The state to be updated, and the new value of is_final
     SetFinal(u32, bool),
+    // Only Turing machines have reject states
+    SetReject(u32, bool),
 
     // The state's ID and its old and new names
     SetStateName(u32, String, String),
+    // The state's ID and its old and new outputs, for models with state outputs
+    SetStateOutput(u32, String, String),
 
     // The state's ID and position
     CreateState(u32, Vec2),
     // The transition to be added
-    CreateTransition(FiniteAutomatonTransition),
+    CreateTransition(ModelTransition),
 
-    // The state, its position, its name, and transitions involving it
-    DeleteState(u32, Vec2, String, Vec<FiniteAutomatonTransition>),
+    // The state, its position, its name, its output if it has one, and transitions involving it
+    DeleteState(u32, Vec2, String, Option<String>, Vec<ModelTransition>),
     // The transition to be deleted
-    DeleteTransition(FiniteAutomatonTransition),
+    DeleteTransition(ModelTransition),
 }
 
 impl Command {
-    pub fn execute(&self, fa: &mut FiniteAutomaton, states: &mut States) {
+    pub fn execute(&self, model: &mut Model, states: &mut States) {
         match self {
-            Self::SetInitial(state, _) => fa.automaton.set_initial(*state),
-            Self::RemoveInitial(_) => fa.automaton.remove_initial(),
-            Self::SetFinal(state, value) => fa.automaton.set_final(*state, *value),
+            Self::SetInitial(state, _) => model.set_initial(*state),
+            Self::RemoveInitial(_) => model.remove_initial(),
+            Self::SetFinal(state, value) => model.set_final(*state, *value),
+            Self::SetReject(state, value) => model.set_reject(*state, *value),
 
             Self::SetStateName(state, _, new_name) => {
                 states.insert_name(*state, new_name.to_string())
             }
+            Self::SetStateOutput(state, _, new_output) => {
+                model.set_state_output(*state, new_output)
+            }
 
             Self::CreateState(state, pos) => {
-                states.try_add_state_with_id(fa, *pos, *state);
+                states.try_add_state_with_id(model, *pos, *state);
             }
-            Self::CreateTransition(transition) => fa.automaton.add_transition(*transition),
+            Self::CreateTransition(transition) => model.add_transition(transition.clone()),
 
-            Self::DeleteState(state, _, _, _) => states.remove_state(fa, *state),
-            Self::DeleteTransition(transition) => fa.automaton.remove_transition(*transition),
+            Self::DeleteState(state, _, _, _, _) => states.remove_state(model, *state),
+            Self::DeleteTransition(transition) => model.remove_transition(transition.clone()),
         }
     }
 
-    pub fn undo(&self, fa: &mut FiniteAutomaton, states: &mut States) {
+    pub fn undo(&self, model: &mut Model, states: &mut States) {
         match self {
             Self::SetInitial(_, old_initial) => {
                 if let Some(state) = *old_initial {
-                    fa.automaton.set_initial(state);
+                    model.set_initial(state);
                 } else {
-                    fa.automaton.remove_initial();
+                    model.remove_initial();
                 }
             }
-            Self::RemoveInitial(state) => fa.automaton.set_initial(*state),
-            Self::SetFinal(state, value) => fa.automaton.set_final(*state, !value),
+            Self::RemoveInitial(state) => model.set_initial(*state),
+            Self::SetFinal(state, value) => model.set_final(*state, !value),
+            Self::SetReject(state, value) => model.set_reject(*state, !value),
 
             Self::SetStateName(state, old_name, _) => {
                 states.insert_name(*state, old_name.to_string())
             }
+            Self::SetStateOutput(state, old_output, _) => {
+                model.set_state_output(*state, old_output)
+            }
 
-            Self::CreateState(state, _) => states.remove_state(fa, *state),
-            Self::CreateTransition(transition) => fa.automaton.remove_transition(*transition),
+            Self::CreateState(state, _) => states.remove_state(model, *state),
+            Self::CreateTransition(transition) => model.remove_transition(transition.clone()),
 
-            Self::DeleteState(id, position, name, transitions) => {
+            Self::DeleteState(id, position, name, output, transitions) => {
                 // TODO: Remember name in the command so it can be undone
-                let succeeded = states.try_add_state_with_id(fa, *position, *id);
+                let succeeded = states.try_add_state_with_id(model, *position, *id);
                 if succeeded {
                     states.insert_name(*id, name.to_string());
-                    for &transition in transitions {
-                        fa.automaton.add_transition(transition)
+                    if let Some(output) = output {
+                        model.set_state_output(*id, output);
+                    }
+                    for transition in transitions {
+                        model.add_transition(transition.clone())
                     }
                 }
             }
-            Self::DeleteTransition(transition) => fa.automaton.add_transition(*transition),
+            Self::DeleteTransition(transition) => model.add_transition(transition.clone()),
         }
     }
 }
@@ -0,0 +1,86 @@
+use crate::{
+    model::*,
+    states::*,
+    top_panel::{Mode, PanelDocument, TOP_PANEL},
+    top_panel_command_handler::TopPanelCommandHandler,
+};
+
+use std::path::{Path, PathBuf};
+
+/// An open document, with everything needed to edit and simulate it, shown in its own tab
+pub struct Document {
+    pub model: Model,
+    pub states: States,
+    // The undo history, and whether there are unsaved changes
+    pub command_handler: TopPanelCommandHandler,
+    // The simulation in progress, if any
+    pub configurations: Configurations,
+
+    // The parts of the document kept by the top panel, which holds them while the
+    // document's tab is open, and hands them back here when another tab is opened
+    pub panel_document: Option<PanelDocument>,
+    pub file_path: Option<PathBuf>,
+    pub mode: Mode,
+}
+
+impl Default for Document {
+    fn default() -> Self {
+        Self::new(Model::default(), States::new())
+    }
+}
+
+impl Document {
+    pub fn new(model: Model, states: States) -> Self {
+        Self {
+            model,
+            states,
+            command_handler: TopPanelCommandHandler::new(),
+            configurations: Configurations::default(),
+            panel_document: None,
+            file_path: None,
+            mode: Mode::Edit,
+        }
+    }
+
+    /// Creates a document edited entirely within the top panel, such as a grammar
+    pub fn with_panel_document(panel_document: PanelDocument) -> Self {
+        Self {
+            panel_document: Some(panel_document),
+            ..Self::default()
+        }
+    }
+
+    /// Replaces the automaton and its layout, forgetting the undo history and any simulation
+    pub fn replace(&mut self, model: Model, states: States) {
+        self.model = model;
+        self.states = states;
+        self.command_handler.reset();
+        self.configurations = Configurations::default();
+    }
+
+    /// Hands the parts of the document kept by the top panel over to it, as its tab is opened
+    pub fn show(&mut self) {
+        TOP_PANEL.with(|panel| {
+            if let Ok(mut panel) = panel.try_borrow_mut() {
+                panel.load_document(self);
+            }
+        });
+    }
+
+    /// Takes back the parts of the document kept by the top panel, as another tab is opened
+    pub fn hide(&mut self) {
+        TOP_PANEL.with(|panel| {
+            if let Ok(mut panel) = panel.try_borrow_mut() {
+                panel.store_document(self);
+            }
+        });
+    }
+}
+
+/// Returns the name of the document's file, or "Untitled" if it has none
+pub fn document_name(file_path: Option<&Path>) -> String {
+    file_path.and_then(Path::file_name).map_or_else(
+        || "Untitled".to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
@@ -1,8 +1,22 @@
-use crate::states::*;
+use crate::{
+    document::Document,
+    model::*,
+    states::*,
+    top_panel::{ComparisonError, Mode, PanelDocument, TOP_PANEL},
+};
 
-use sugarcubes_core::automata::{
-    finite_automaton::{FiniteAutomaton, FiniteAutomatonTransition},
-    Transition,
+use sugarcubes_core::{
+    automata::{
+        finite_automaton::{FiniteAutomaton, StatePartition},
+        mealy_machine::MealyMachine,
+        moore_machine::MooreMachine,
+        pushdown_automaton::PushdownAutomaton,
+        turing_machine::{TapeAction, TuringMachine},
+        Transition, EMPTY_STRING,
+    },
+    grammar::{Grammar, Production, PushdownConstruction, SlrParseTable, TransformError},
+    jff::{read_jff, JffAutomaton, JffDocument, JffError},
+    regex::Regex,
 };
 
 use macroquad::prelude::*;
@@ -11,7 +25,11 @@ use xmltree::{Element, XMLNode};
 #[cfg(target_arch = "wasm32")]
 use sapp_jsutils::JsObject;
 
-use std::io::BufWriter;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
 
 #[cfg(target_arch = "wasm32")]
 extern "C" {
@@ -25,106 +43,312 @@ const SCALE_FACTOR: f32 = 2.0;
 // Defines actions that read or write the entire document state
 pub enum DocumentCommand {
     NewFile,
-    OpenJFF(String),
+    // Replace the document with a regular expression with the given text
+    NewRegex(String),
+    // Replace the document with a Turing machine with the given number of tapes
+    NewTuringMachine(usize),
+    NewMealyMachine,
+    NewMooreMachine,
+    NewPushdownAutomaton,
+    NewGrammar,
+    // Replace the document with the contents of a JFF file, and the path of the file if known
+    OpenJFF(String, Option<PathBuf>),
+    // Save the document to its file
     SaveJFF,
+    // Save the document to a new file, which becomes the document's file
+    SaveJFFAs(PathBuf),
+    // The conversions below open their result in a new tab, keeping the original document
+    ConvertToDFA,
+    MinimizeDFA,
+    // Open the DFA with a state for each group of a partition completed step by step
+    MinimizeDFAWithPartition(StatePartition),
+    ConvertRegexToNFA,
+    // Open the DFA of the grammar's LR(0) item sets
+    OpenItemAutomaton,
+    // Open a pushdown automaton accepting the grammar's language
+    ConvertGrammarToPDA(PushdownConstruction),
+    // Open a grammar generating the pushdown automaton's language
+    ConvertPDAToGrammar,
+    // Open a finite automaton accepting the right-linear grammar's language
+    ConvertGrammarToFA,
+    // Open a right-linear grammar generating the finite automaton's language
+    ConvertFAToGrammar,
+    // Compare the document's language against the automaton in another JFF file
+    CompareJFF(String),
 }
 
 impl DocumentCommand {
-    pub fn execute(&self, fa: &mut FiniteAutomaton, states: &mut States) {
-        match &*self {
+    /// Executes the command on the document. Conversions leave the document as it was,
+    /// and return what it was converted into, to be opened in a new tab.
+    pub fn execute(&self, document: &mut Document) -> Option<Document> {
+        match self {
             Self::NewFile => {
-                // TODO: Alert the user if they have unsaved changes
-                // possibly by packing all save-invalidating commands (e.g. moving states,
-                // deleting) into a dispatcher
-                *fa = FiniteAutomaton::default();
-                *states = States::new();
+                document.replace(Model::default(), States::new());
+                set_panel_document(None);
+                set_file_path(None);
+            }
+            Self::NewRegex(regex) => {
+                document.replace(Model::default(), States::new());
+                set_panel_document(Some(PanelDocument::Regex(regex.clone())));
+                set_file_path(None);
+            }
+            Self::NewTuringMachine(tape_count) => {
+                document.replace(
+                    Model::TuringMachine(TuringMachine::with_tapes(*tape_count)),
+                    States::new(),
+                );
+                set_panel_document(None);
+                set_file_path(None);
+            }
+            Self::NewMealyMachine => {
+                document.replace(Model::MealyMachine(MealyMachine::default()), States::new());
+                set_panel_document(None);
+                set_file_path(None);
             }
-            Self::OpenJFF(content_string) => {
-                // TODO: Report errors to user
-                let _ = self.open_jff(content_string, fa, states);
+            Self::NewMooreMachine => {
+                document.replace(Model::MooreMachine(MooreMachine::default()), States::new());
+                set_panel_document(None);
+                set_file_path(None);
             }
+            Self::NewPushdownAutomaton => {
+                document.replace(
+                    Model::PushdownAutomaton(PushdownAutomaton::default()),
+                    States::new(),
+                );
+                set_panel_document(None);
+                set_file_path(None);
+            }
+            Self::NewGrammar => {
+                document.replace(Model::default(), States::new());
+                set_panel_document(Some(PanelDocument::Grammar(Grammar::default())));
+                set_file_path(None);
+            }
+            Self::OpenJFF(content_string, path) => match read_jff(content_string) {
+                Ok((jff_document, warnings)) => {
+                    self.open_jff(jff_document, document);
+                    set_file_path(path.clone());
+                    report_jff_import(None, warnings);
+                }
+                Err(error) => report_jff_import(Some(error), Vec::new()),
+            },
             Self::SaveJFF => {
-                // TODO: Report errors to user
-                let _ = self.save_jff(fa, states);
+                let structure = self.jff_structure(&document.model, &mut document.states);
+                match write_jff(&structure, get_file_path().as_deref()) {
+                    Ok(()) => document.command_handler.mark_clean(),
+                    Err(error) => report_file_error(error),
+                }
             }
-        }
-    }
-
-    fn open_jff(
-        &self,
-        content_string: &str,
-        fa: &mut FiniteAutomaton,
-        states: &mut States,
-    ) -> Option<()> {
-        let mut new_fa = FiniteAutomaton::default();
-        let mut new_states = States::new();
-
-        let element = Element::parse(content_string.as_bytes()).ok()?;
-        // TODO: Error if type is wrong. Eventually, treat different types differently.
-        let _model_type = element.get_child("type")?.get_text()?;
-        let automaton = element.get_child("automaton")?;
-
-        // First, read and add all the states
-        for child in &automaton.children {
-            if let XMLNode::Element(element) = child {
-                if element.name.as_str() == "state" {
-                    let id: u32 = element.attributes.get("id")?.parse().ok()?;
-                    let name: &String = element.attributes.get("name")?;
-                    let x: f32 = element.get_child("x")?.get_text()?.parse().ok()?;
-                    let y: f32 = element.get_child("y")?.get_text()?.parse().ok()?;
-                    let is_initial = element.get_child("initial").is_some();
-                    let is_final = element.get_child("final").is_some();
-                    new_states.try_add_state_with_id(
-                        &mut new_fa,
-                        vec2(x * SCALE_FACTOR, y * SCALE_FACTOR),
-                        id,
-                    );
-                    new_states.insert_name(id, name.clone());
-
-                    if is_initial {
-                        new_fa.automaton.set_initial(id);
+            Self::SaveJFFAs(path) => {
+                let structure = self.jff_structure(&document.model, &mut document.states);
+                match write_jff(&structure, Some(path)) {
+                    Ok(()) => {
+                        set_file_path(Some(path.clone()));
+                        document.command_handler.mark_clean();
                     }
-
-                    if is_final {
-                        new_fa.automaton.set_final(id, true);
+                    Err(error) => report_file_error(error),
+                }
+            }
+            Self::ConvertToDFA => {
+                if let Some(fa) = document.model.as_finite_automaton() {
+                    let determinized = fa.determinize();
+                    return Some(determinized_document(determinized, &mut document.states));
+                }
+            }
+            Self::MinimizeDFA => {
+                if let Some(minimized) = document
+                    .model
+                    .as_finite_automaton()
+                    .and_then(FiniteAutomaton::minimize)
+                {
+                    return Some(minimized_document(minimized, &mut document.states));
+                }
+            }
+            Self::MinimizeDFAWithPartition(partition) => {
+                if let Some(minimized) = partition.to_automaton() {
+                    return Some(minimized_document(minimized, &mut document.states));
+                }
+            }
+            Self::ConvertRegexToNFA => {
+                if let Some(PanelDocument::Regex(regex)) = get_panel_document() {
+                    if let Ok(regex) = regex.parse::<Regex>() {
+                        return Some(auto_placed_document(Model::FiniteAutomaton(
+                            regex.to_finite_automaton(),
+                        )));
+                    }
+                }
+            }
+            Self::OpenItemAutomaton => {
+                if let Some(PanelDocument::Grammar(grammar)) = get_panel_document() {
+                    if let Some(table) = SlrParseTable::new(&grammar) {
+                        let mut item_document =
+                            auto_placed_document(Model::FiniteAutomaton(table.item_automaton()));
+                        // States are named after their item sets, as in the parse table
+                        for state in item_document.model.states() {
+                            item_document
+                                .states
+                                .insert_name(state, format!("I{}", state));
+                        }
+                        return Some(item_document);
+                    }
+                }
+            }
+            Self::ConvertGrammarToPDA(construction) => {
+                if let Some(PanelDocument::Grammar(grammar)) = get_panel_document() {
+                    if let Ok(pda) = grammar.to_pushdown_automaton(*construction) {
+                        return Some(auto_placed_document(Model::PushdownAutomaton(pda)));
+                    }
+                }
+            }
+            Self::ConvertPDAToGrammar => {
+                if let Model::PushdownAutomaton(pda) = &document.model {
+                    match pda.to_grammar() {
+                        Ok(grammar) => {
+                            return Some(converted_document(Document::with_panel_document(
+                                PanelDocument::Grammar(grammar),
+                            )));
+                        }
+                        Err(error) => report_conversion_error(error),
+                    }
+                }
+            }
+            Self::ConvertGrammarToFA => {
+                if let Some(PanelDocument::Grammar(grammar)) = get_panel_document() {
+                    if let Ok((fa, variables)) = grammar.to_finite_automaton() {
+                        let mut fa_document = auto_placed_document(Model::FiniteAutomaton(fa));
+                        // States are named after their variables, as in the grammar
+                        for (&state, variable) in &variables {
+                            fa_document.states.insert_name(state, variable.to_string());
+                        }
+                        return Some(fa_document);
                     }
                 }
             }
+            Self::ConvertFAToGrammar => {
+                if let Some(fa) = document.model.as_finite_automaton() {
+                    // States named by a single variable keep that name in the grammar
+                    let states = &mut document.states;
+                    let names = document
+                        .model
+                        .states()
+                        .into_iter()
+                        .map(|state| (state, states.get_name(state)))
+                        .collect();
+                    match fa.to_grammar(&names) {
+                        Ok(grammar) => {
+                            return Some(converted_document(Document::with_panel_document(
+                                PanelDocument::Grammar(grammar),
+                            )));
+                        }
+                        Err(error) => report_conversion_error(error),
+                    }
+                }
+            }
+            Self::CompareJFF(content_string) => {
+                let other_document = match read_jff(content_string) {
+                    Ok((other_document, warnings)) => {
+                        report_jff_import(None, warnings);
+                        other_document
+                    }
+                    Err(error) => {
+                        report_jff_import(Some(error), Vec::new());
+                        return None;
+                    }
+                };
+                let fa = match document.model.as_finite_automaton() {
+                    Some(fa) => fa,
+                    None => {
+                        report_comparison_error(ComparisonError::ThisDocument);
+                        return None;
+                    }
+                };
+                if let JffDocument::Automaton {
+                    automaton: JffAutomaton::FiniteAutomaton(other_fa),
+                    ..
+                } = other_document
+                {
+                    let equivalence = fa.equivalence(&other_fa);
+                    TOP_PANEL.with(|panel| {
+                        if let Ok(mut panel) = panel.try_borrow_mut() {
+                            panel.equivalence_result = Some(equivalence);
+                        }
+                    });
+                } else {
+                    report_comparison_error(ComparisonError::OtherDocument);
+                }
+            }
         }
+        None
+    }
 
-        // After reading all the states, take a second pass to read and add all the transitions
-        for child in &automaton.children {
-            if let XMLNode::Element(element) = child {
-                if element.name.as_str() == "transition" {
-                    let from: u32 = element.get_child("from")?.get_text()?.parse().ok()?;
-                    let to: u32 = element.get_child("to")?.get_text()?.parse().ok()?;
-                    let read = element.get_child("read")?.get_text()?.chars().next()?;
-                    new_fa
-                        .automaton
-                        .add_transition(FiniteAutomatonTransition::new(from, to, read));
+    fn open_jff(&self, jff_document: JffDocument, document: &mut Document) {
+        match jff_document {
+            JffDocument::Regex(expression) => {
+                document.replace(Model::default(), States::new());
+                set_panel_document(Some(PanelDocument::Regex(expression)));
+            }
+            JffDocument::Grammar(grammar) => {
+                document.replace(Model::default(), States::new());
+                set_panel_document(Some(PanelDocument::Grammar(grammar)));
+            }
+            JffDocument::Automaton {
+                automaton,
+                states: jff_states,
+            } => {
+                let mut states = States::new();
+                for state in jff_states {
+                    states.insert_position(
+                        state.id,
+                        vec2(state.x * SCALE_FACTOR, state.y * SCALE_FACTOR),
+                    );
+                    states.insert_name(state.id, state.name);
                 }
+                document.replace(Model::from(automaton), states);
+                set_panel_document(None);
             }
         }
+    }
 
-        *fa = new_fa;
-        *states = new_states;
-
-        Some(())
+    // Build the JFF structure of whichever kind of document is open
+    fn jff_structure(&self, model: &Model, states: &mut States) -> Element {
+        match get_panel_document() {
+            Some(PanelDocument::Regex(regex)) => self.regex_structure(&regex),
+            Some(PanelDocument::Grammar(grammar)) => self.grammar_structure(&grammar),
+            None => self.automaton_structure(model, states),
+        }
     }
 
-    fn save_jff(&self, fa: &FiniteAutomaton, states: &mut States) -> Option<()> {
+    fn automaton_structure(&self, model: &Model, states: &mut States) -> Element {
         let mut structure = Element::new("structure");
 
         let mut model_type = Element::new("type");
-        model_type.children.push(XMLNode::Text("fa".to_string()));
+        let type_name = match model {
+            Model::FiniteAutomaton(_) => "fa",
+            Model::TuringMachine(_) => "turing",
+            Model::MealyMachine(_) => "mealy",
+            Model::MooreMachine(_) => "moore",
+            Model::PushdownAutomaton(_) => "pda",
+        };
+        model_type
+            .children
+            .push(XMLNode::Text(type_name.to_string()));
         structure.children.push(XMLNode::Element(model_type));
 
+        if let Model::TuringMachine(tm) = model {
+            if tm.tape_count > 1 {
+                let mut tapes = Element::new("tapes");
+                tapes
+                    .children
+                    .push(XMLNode::Text(tm.tape_count.to_string()));
+                structure.children.push(XMLNode::Element(tapes));
+            }
+        }
+
         let mut automaton = Element::new("automaton");
 
         automaton
             .children
             .push(XMLNode::Comment("The list of states.".to_string()));
-        for id in fa.automaton.states_iter() {
+        for id in model.states().iter() {
             let mut state = Element::new("state");
 
             let id_string = id.to_string();
@@ -145,23 +369,35 @@ impl DocumentCommand {
                 .push(XMLNode::Text((y / SCALE_FACTOR).to_string()));
             state.children.push(XMLNode::Element(y_element));
 
-            if fa.automaton.initial() == Some(*id) {
+            if model.initial() == Some(*id) {
                 state
                     .children
                     .push(XMLNode::Element(Element::new("initial")));
             }
 
-            if fa.automaton.is_final(*id) {
+            if model.is_final(*id) {
                 state.children.push(XMLNode::Element(Element::new("final")));
             }
 
+            if model.is_reject(*id) == Some(true) {
+                state
+                    .children
+                    .push(XMLNode::Element(Element::new("reject")));
+            }
+
+            if let Some(output) = model.state_output(*id) {
+                state
+                    .children
+                    .push(XMLNode::Element(output_element("output", output)));
+            }
+
             automaton.children.push(XMLNode::Element(state));
         }
 
         automaton
             .children
             .push(XMLNode::Comment("The list of transitions.".to_string()));
-        for transition in fa.automaton.transitions() {
+        for transition in model.transitions() {
             let mut transition_element = Element::new("transition");
 
             let mut from = Element::new("from");
@@ -173,10 +409,62 @@ impl DocumentCommand {
             to.children.push(XMLNode::Text(transition.to().to_string()));
             transition_element.children.push(XMLNode::Element(to));
 
-            let mut read = Element::new("read");
-            read.children
-                .push(XMLNode::Text(transition.symbol().to_string()));
-            transition_element.children.push(XMLNode::Element(read));
+            match (model, &transition) {
+                // JFLAP writes an empty element for the empty string
+                (_, ModelTransition::FiniteAutomaton(transition)) => {
+                    transition_element
+                        .children
+                        .push(XMLNode::Element(output_element(
+                            "read",
+                            transition.read().to_string(),
+                        )));
+
+                    // JFLAP labels Moore machine transitions with the output of the state they enter
+                    if let Some(output) = model.state_output(transition.to()) {
+                        transition_element
+                            .children
+                            .push(XMLNode::Element(output_element("transout", output)));
+                    }
+                }
+                (_, ModelTransition::MealyMachine(transition)) => {
+                    let mut read = Element::new("read");
+                    read.children
+                        .push(XMLNode::Text(transition.symbol().to_string()));
+                    transition_element.children.push(XMLNode::Element(read));
+                    transition_element
+                        .children
+                        .push(XMLNode::Element(output_element(
+                            "transout",
+                            transition.output().to_string(),
+                        )));
+                }
+                (_, ModelTransition::PushdownAutomaton(transition)) => {
+                    let read = if transition.symbol() == EMPTY_STRING {
+                        String::new()
+                    } else {
+                        transition.symbol().to_string()
+                    };
+                    for (name, text) in &[
+                        ("read", read),
+                        ("pop", transition.pop().to_string()),
+                        ("push", transition.push().to_string()),
+                    ] {
+                        transition_element
+                            .children
+                            .push(XMLNode::Element(output_element(name, text.clone())));
+                    }
+                }
+                (Model::TuringMachine(tm), ModelTransition::TuringMachine(transition)) => {
+                    for (i, action) in transition.actions().iter().enumerate() {
+                        for element in
+                            write_tape_action(action, i + 1, tm.tape_count, tm.blank_symbol)
+                        {
+                            transition_element.children.push(XMLNode::Element(element));
+                        }
+                    }
+                }
+                _ => {}
+            }
 
             automaton
                 .children
@@ -185,15 +473,257 @@ impl DocumentCommand {
 
         structure.children.push(XMLNode::Element(automaton));
 
+        structure
+    }
+
+    fn regex_structure(&self, regex: &str) -> Element {
+        let mut structure = Element::new("structure");
+
+        let mut model_type = Element::new("type");
+        model_type.children.push(XMLNode::Text("re".to_string()));
+        structure.children.push(XMLNode::Element(model_type));
+
+        let mut expression = Element::new("expression");
+        expression.children.push(XMLNode::Text(regex.to_string()));
+        structure.children.push(XMLNode::Element(expression));
+
+        structure
+    }
+
+    fn grammar_structure(&self, grammar: &Grammar) -> Element {
+        let mut structure = Element::new("structure");
+
+        let mut model_type = Element::new("type");
+        model_type
+            .children
+            .push(XMLNode::Text("grammar".to_string()));
+        structure.children.push(XMLNode::Element(model_type));
+
+        // Write the start symbol's productions first, so JFLAP finds the start symbol
+        let (start_productions, other_productions): (Vec<&Production>, Vec<&Production>) = grammar
+            .productions
+            .iter()
+            .partition(|production| production.single_variable_lhs() == Some(grammar.start_symbol));
+        for production in start_productions.into_iter().chain(other_productions) {
+            let mut production_element = Element::new("production");
+            for (name, text) in &[("left", &production.lhs), ("right", &production.rhs)] {
+                let mut side = Element::new(name);
+                if !text.is_empty() {
+                    side.children.push(XMLNode::Text(text.to_string()));
+                }
+                production_element.children.push(XMLNode::Element(side));
+            }
+            structure
+                .children
+                .push(XMLNode::Element(production_element));
+        }
+
+        structure
+    }
+}
+
+// Create the read, write and move elements for the action on the given tape (numbered from 1),
+// only labelling them with the tape for multi-tape machines
+fn write_tape_action(
+    action: &TapeAction,
+    tape: usize,
+    tape_count: usize,
+    blank_symbol: char,
+) -> Vec<Element> {
+    let symbol_text = |symbol: char| {
+        if symbol == blank_symbol {
+            String::new()
+        } else {
+            symbol.to_string()
+        }
+    };
+
+    [
+        ("read", symbol_text(action.read)),
+        ("write", symbol_text(action.write)),
+        ("move", action.direction.to_char().to_string()),
+    ]
+    .iter()
+    .map(|(name, text)| {
+        let mut element = Element::new(name);
+        if tape_count > 1 {
+            element
+                .attributes
+                .insert("tape".to_owned(), tape.to_string());
+        }
+        if !text.is_empty() {
+            element.children.push(XMLNode::Text(text.clone()));
+        }
+        element
+    })
+    .collect()
+}
+
+// Create an element holding an output or stack string, which is left empty for an empty string
+fn output_element(name: &str, output: String) -> Element {
+    let mut element = Element::new(name);
+    if !output.is_empty() {
+        element.children.push(XMLNode::Text(output));
+    }
+    element
+}
+
+// Serialize a JFF structure and write it to the given file,
+// or on the web, hand it to the user to save wherever they choose
+fn write_jff(structure: &Element, path: Option<&Path>) -> Result<(), String> {
+    let mut content = Vec::new();
+    // TODO: Consider formatting the file better, e.g. with newlines
+    structure
+        .write(&mut content)
+        .map_err(|error| error.to_string())?;
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = path;
         unsafe {
-            let mut content = String::new();
-            let buffer = BufWriter::new(content.as_mut_vec());
-            // TODO: Consider formatting the file better, e.g. with newlines
-            structure.write(buffer).ok()?;
-            #[cfg(target_arch = "wasm32")]
-            save_jff_file(JsObject::string(&content));
+            save_jff_file(JsObject::string(&String::from_utf8_lossy(&content)));
         }
+        Ok(())
+    }
 
-        Some(())
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let path = path.ok_or_else(|| "The document has no file to save to".to_string())?;
+        fs::write(path, content).map_err(|error| format!("{}: {}", path.display(), error))
     }
 }
+
+// Switch the document to or from one edited in the top panel, such as a regular expression
+fn set_panel_document(document: Option<PanelDocument>) {
+    TOP_PANEL.with(|panel| {
+        if let Ok(mut panel) = panel.try_borrow_mut() {
+            panel.panel_document = document;
+            panel.mode = Mode::Edit;
+        }
+    });
+}
+
+// A new untitled document holding the result of a conversion, which starts out unsaved
+// so that the user is asked before it is discarded
+fn converted_document(mut document: Document) -> Document {
+    document.command_handler.mark_dirty();
+    document
+}
+
+// A converted document holding the automaton, with its states placed automatically
+fn auto_placed_document(model: Model) -> Document {
+    let states = States::auto_place(&model);
+    converted_document(Document::new(model, states))
+}
+
+// A converted document holding a determinized automaton, along with the subset
+// of original states behind each of its states
+fn determinized_document(
+    (dfa, subsets): (FiniteAutomaton, BTreeMap<u32, BTreeSet<u32>>),
+    states: &mut States,
+) -> Document {
+    let model = Model::FiniteAutomaton(dfa);
+    let mut new_states = States::auto_place(&model);
+
+    // Each state is named after its subset, like {q0,q2}; states added to split
+    // transitions reading several symbols have no name of their own
+    for (&id, subset) in &subsets {
+        let names: Vec<String> = subset
+            .iter()
+            .map(|&state| match states.get_name(state) {
+                name if name.is_empty() => States::default_name(state),
+                name => name,
+            })
+            .collect();
+        new_states.insert_name(id, format!("{{{}}}", names.join(",")));
+    }
+    converted_document(Document::new(model, new_states))
+}
+
+// A converted document holding a minimized automaton, along with the original states
+// merged into each of its states
+fn minimized_document(
+    (minimal_fa, merged): (FiniteAutomaton, BTreeMap<u32, BTreeSet<u32>>),
+    states: &mut States,
+) -> Document {
+    // Each merged state takes the position of its first original state,
+    // and is named after all of its original states
+    let mut new_states = States::new();
+    for (&id, originals) in &merged {
+        let first = *originals.iter().next().unwrap();
+        new_states.insert_position(id, *states.get_position(first));
+        let names: Vec<String> = originals
+            .iter()
+            .map(|&state| states.get_name(state))
+            .collect();
+        new_states.insert_name(id, names.join(","));
+    }
+    converted_document(Document::new(
+        Model::FiniteAutomaton(minimal_fa),
+        new_states,
+    ))
+}
+
+// Tell the user why the document could not be converted
+fn report_conversion_error(error: TransformError) {
+    TOP_PANEL.with(|panel| {
+        if let Ok(mut panel) = panel.try_borrow_mut() {
+            panel.conversion_error = Some(error);
+        }
+    });
+}
+
+// Tell the user which of the documents being compared is not a finite automaton
+fn report_comparison_error(error: ComparisonError) {
+    TOP_PANEL.with(|panel| {
+        if let Ok(mut panel) = panel.try_borrow_mut() {
+            panel.comparison_error = Some(error);
+        }
+    });
+}
+
+// Remember the file the document was opened from or saved to
+fn set_file_path(path: Option<PathBuf>) {
+    TOP_PANEL.with(|panel| {
+        if let Ok(mut panel) = panel.try_borrow_mut() {
+            panel.set_file_path(path);
+        }
+    });
+}
+
+fn get_file_path() -> Option<PathBuf> {
+    TOP_PANEL.with(|panel| {
+        panel
+            .try_borrow()
+            .ok()
+            .and_then(|panel| panel.file_path.clone())
+    })
+}
+
+// Tell the user why the document could not be saved
+fn report_file_error(error: String) {
+    TOP_PANEL.with(|panel| {
+        if let Ok(mut panel) = panel.try_borrow_mut() {
+            panel.file_error = Some(error);
+        }
+    });
+}
+
+// Tell the user why a JFF file could not be read, or what was skipped while reading it
+fn report_jff_import(error: Option<JffError>, warnings: Vec<JffError>) {
+    TOP_PANEL.with(|panel| {
+        if let Ok(mut panel) = panel.try_borrow_mut() {
+            panel.jff_error = error;
+            panel.jff_warnings = warnings;
+        }
+    });
+}
+
+fn get_panel_document() -> Option<PanelDocument> {
+    TOP_PANEL.with(|panel| {
+        panel
+            .try_borrow()
+            .ok()
+            .and_then(|panel| panel.panel_document.clone())
+    })
+}
@@ -1,20 +1,21 @@
 extern crate xmltree;
 
 mod command;
+mod document;
 mod document_command;
+mod model;
 mod states;
 mod top_panel;
 mod top_panel_command_handler;
 mod transitions;
 
 use crate::{
-    command::*, document_command::*, states::*, top_panel::*, top_panel_command_handler::*,
-    transitions::*,
+    command::*, document::*, document_command::*, model::*, states::*, top_panel::*, transitions::*,
 };
 
 use sugarcubes_core::automata::{
     finite_automaton::{FiniteAutomaton, FiniteAutomatonTransition},
-    SimulateAutomaton, Transition, EMPTY_STRING,
+    EMPTY_STRING,
 };
 
 use macroquad::prelude::*;
@@ -22,22 +23,40 @@ use macroquad::ui::{hash, root_ui, widgets, Skin};
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use sapp_jsutils::JsObject;
 
 #[no_mangle]
-extern "C" fn open_jff_file(content: JsObject) {
+extern "C" fn open_jff_file(content: JsObject, file_name: JsObject) {
     let mut content_string = String::new();
     content.to_string(&mut content_string);
+    let mut file_name_string = String::new();
+    file_name.to_string(&mut file_name_string);
 
     DOCUMENT_COMMAND_BUFFER.with(|buff| {
         if let Ok(mut buff) = buff.try_borrow_mut() {
-            buff.push(DocumentCommand::OpenJFF(content_string));
+            buff.push(DocumentCommand::OpenJFF(
+                content_string,
+                Some(PathBuf::from(file_name_string)),
+            ));
         }
     });
 }
 
-thread_local! { pub static DOCUMENT_COMMAND_BUFFER: RefCell<Vec<DocumentCommand>> = RefCell::new(Vec::new()); }
+#[no_mangle]
+extern "C" fn compare_jff_file(content: JsObject) {
+    let mut content_string = String::new();
+    content.to_string(&mut content_string);
+
+    DOCUMENT_COMMAND_BUFFER.with(|buff| {
+        if let Ok(mut buff) = buff.try_borrow_mut() {
+            buff.push(DocumentCommand::CompareJFF(content_string));
+        }
+    });
+}
+
+thread_local! { pub static DOCUMENT_COMMAND_BUFFER: RefCell<Vec<DocumentCommand>> = const { RefCell::new(Vec::new()) }; }
 
 const DOUBLE_CLICK_DELAY: f64 = 0.25;
 
@@ -59,38 +78,41 @@ async fn main() {
         }
     };
 
-    let mut fa = FiniteAutomaton::default();
+    let mut model = Model::FiniteAutomaton(FiniteAutomaton::default());
     let mut states = States::new();
-    let s0 = states.add_state(&mut fa, vec2(200., 270.));
-    let s1 = states.add_state(&mut fa, vec2(400., 170.));
-    let s2 = states.add_state(&mut fa, vec2(400., 370.));
-    let s3 = states.add_state(&mut fa, vec2(600., 370.));
-
-    fa.automaton.set_initial(s0);
-    fa.automaton.set_final(s2, true);
-
-    fa.automaton
-        .add_transition(FiniteAutomatonTransition::new(s0, s1, 'x'));
-    fa.automaton
-        .add_transition(FiniteAutomatonTransition::new(s0, s2, EMPTY_STRING));
-    fa.automaton
-        .add_transition(FiniteAutomatonTransition::new(s1, s1, 'a'));
-    fa.automaton
-        .add_transition(FiniteAutomatonTransition::new(s1, s2, 'a'));
-    fa.automaton
-        .add_transition(FiniteAutomatonTransition::new(s1, s2, 'b'));
-    fa.automaton
-        .add_transition(FiniteAutomatonTransition::new(s2, s3, 'b'));
-    fa.automaton
-        .add_transition(FiniteAutomatonTransition::new(s3, s2, 'c'));
-    fa.automaton
-        .add_transition(FiniteAutomatonTransition::new(s3, s2, 'f'));
-    fa.automaton
-        .add_transition(FiniteAutomatonTransition::new(s3, s3, 'd'));
-    fa.automaton
-        .add_transition(FiniteAutomatonTransition::new(s3, s3, 'x'));
-
-    let mut configurations = Vec::new();
+    let s0 = states.add_state(&mut model, vec2(200., 270.));
+    let s1 = states.add_state(&mut model, vec2(400., 170.));
+    let s2 = states.add_state(&mut model, vec2(400., 370.));
+    let s3 = states.add_state(&mut model, vec2(600., 370.));
+
+    model.set_initial(s0);
+    model.set_final(s2, true);
+
+    for &(from, to, symbol) in &[
+        (s0, s1, 'x'),
+        (s0, s2, EMPTY_STRING),
+        (s1, s1, 'a'),
+        (s1, s2, 'a'),
+        (s1, s2, 'b'),
+        (s2, s3, 'b'),
+        (s3, s2, 'c'),
+        (s3, s2, 'f'),
+        (s3, s3, 'd'),
+        (s3, s3, 'x'),
+    ] {
+        model.add_transition(ModelTransition::FiniteAutomaton(
+            FiniteAutomatonTransition::new(from, to, symbol),
+        ));
+    }
+
+    let mut documents = vec![Document::new(model, states)];
+
+    // Closing the window asks about unsaved changes the same way as quitting from the menu
+    #[cfg(not(target_arch = "wasm32"))]
+    prevent_quit();
+    let mut current_document = 0;
+    // Whether the current tab changed during this frame
+    let mut switched_tab = false;
 
     let gl = unsafe { get_internal_gl().quad_gl };
 
@@ -101,10 +123,12 @@ async fn main() {
     // The offset of the click relative to the center of the selected state,
     // so that the mouse "grabs" the state at the point of the initial click
     let mut state_drag_offset = Vec2::ZERO;
+    // Where the dragged state started, to tell whether dragging it moved it
+    let mut state_drag_start = Vec2::ZERO;
     let mut selected_state: Option<u32> = None;
     let mut dragging_selected = false;
 
-    let mut selected_transition: Option<FiniteAutomatonTransition> = None;
+    let mut selected_transition: Option<ModelTransition> = None;
 
     // If the user is drawing a new transition starting on a state, its ID is in here
     let mut creating_transition_from: Option<u32> = None;
@@ -112,8 +136,6 @@ async fn main() {
     // If the user is editing a transition, this holds its (position, text, state_from, state_to)
     let mut editing_transition: Option<(Vec2, String, u32, u32)> = None;
 
-    let mut top_panel_command_handler = TopPanelCommandHandler::new();
-
     let mut last_click_time = 0.;
 
     loop {
@@ -122,27 +144,43 @@ async fn main() {
         DOCUMENT_COMMAND_BUFFER.with(|buff| {
             if let Ok(mut buff) = buff.try_borrow_mut() {
                 while let Some(document_command) = buff.pop() {
-                    document_command.execute(&mut fa, &mut states);
+                    if let Some(converted) =
+                        document_command.execute(&mut documents[current_document])
+                    {
+                        open_tab(&mut documents, &mut current_document, converted);
+                        switched_tab = true;
+                    }
                 }
             }
         });
 
         // Copy state from the top panel
-        let (top_panel_width, top_panel_height, top_panel_mode, top_panel_contains_mouse) =
-            TOP_PANEL
-                .with(|panel| {
-                    panel
-                        .try_borrow()
-                        .ok()
-                        .map(|panel| (panel.width, panel.height, panel.mode, panel.contains_mouse))
+        let (
+            top_panel_width,
+            top_panel_height,
+            top_panel_mode,
+            top_panel_contains_mouse,
+            editing_panel_document,
+        ) = TOP_PANEL
+            .with(|panel| {
+                panel.try_borrow().ok().map(|panel| {
+                    (
+                        panel.width,
+                        panel.height,
+                        panel.mode,
+                        panel.contains_mouse,
+                        panel.panel_document.is_some(),
+                    )
                 })
-                .unwrap();
+            })
+            .unwrap();
 
         // Process keys, mouse etc.
         let screen_mouse_position = Vec2::from(mouse_position());
         let mouse_position: Vec2 = screen_mouse_position - vec2(top_panel_width, top_panel_height);
 
-        if let Mode::Edit = top_panel_mode {
+        let document = &mut documents[current_document];
+        if matches!(top_panel_mode, Mode::Edit) && !editing_panel_document {
             if !top_panel_contains_mouse && is_mouse_button_pressed(MouseButton::Left) {
                 let new_click_time = get_time();
 
@@ -150,22 +188,30 @@ async fn main() {
                 if last_click_time > 0. && new_click_time - last_click_time <= DOUBLE_CLICK_DELAY {
                     creating_transition_from = None;
 
-                    if let Some(state) = states.point_in_some_state(mouse_position, &fa) {
+                    if let Some(state) = document
+                        .states
+                        .point_in_some_state(mouse_position, &document.model)
+                    {
                         creating_transition_from = Some(state);
                     } else {
-                        let id = fa.automaton.get_next_state_id();
-                        top_panel_command_handler.execute(
+                        let id = document.model.get_next_state_id();
+                        document.command_handler.execute(
                             Command::CreateState(id, mouse_position),
-                            &mut fa,
-                            &mut states,
+                            &mut document.model,
+                            &mut document.states,
                         );
                         selected_state = Some(id);
                         state_drag_offset = Vec2::ZERO;
+                        state_drag_start = mouse_position;
                         dragging_selected = true;
                     }
-                } else if let Some(state) = states.point_in_some_state(mouse_position, &fa) {
+                } else if let Some(state) = document
+                    .states
+                    .point_in_some_state(mouse_position, &document.model)
+                {
                     selected_state = Some(state);
-                    state_drag_offset = *states.get_position(state) - mouse_position;
+                    state_drag_offset = *document.states.get_position(state) - mouse_position;
+                    state_drag_start = *document.states.get_position(state);
                     dragging_selected = true;
                 }
 
@@ -174,16 +220,24 @@ async fn main() {
 
             if is_mouse_button_released(MouseButton::Left) {
                 if dragging_selected {
+                    if let Some(selected) = selected_state {
+                        if *document.states.get_position(selected) != state_drag_start {
+                            document.command_handler.mark_dirty();
+                        }
+                    }
                     selected_state = None;
                 }
 
                 // If the user releases over a state while creating a transition,
                 // connect the two states
                 if let Some(from) = creating_transition_from {
-                    if let Some(to) = states.point_in_some_state(mouse_position, &fa) {
+                    if let Some(to) = document
+                        .states
+                        .point_in_some_state(mouse_position, &document.model)
+                    {
                         let middle = {
-                            let position_from = *states.get_position(from);
-                            let position_to = *states.get_position(to);
+                            let position_from = *document.states.get_position(from);
+                            let position_to = *document.states.get_position(to);
                             position_from.lerp(position_to, 0.5)
                         } - transition_input_size / 2.;
                         editing_transition = Some((middle, "".to_string(), from, to));
@@ -201,7 +255,10 @@ async fn main() {
                             mouse_position + vec2(top_panel_width, top_panel_height);
                     })
                 });
-                if let Some(state) = states.point_in_some_state(mouse_position, &fa) {
+                if let Some(state) = document
+                    .states
+                    .point_in_some_state(mouse_position, &document.model)
+                {
                     selected_state = Some(state);
                     dragging_selected = false;
                     selected_transition = None;
@@ -216,33 +273,109 @@ async fn main() {
             .with(|panel| {
                 panel.try_borrow_mut().ok().map(|mut panel| {
                     panel.ui(
-                        &fa,
-                        &mut states,
-                        &mut configurations,
+                        &mut documents,
+                        current_document,
                         &mouse_position,
                         &mut selected_state,
                         &mut selected_transition,
-                        top_panel_command_handler.can_undo(),
-                        top_panel_command_handler.can_redo(),
                     )
                 })
             })
             .unwrap();
 
+        let command_opt = if is_quit_requested() {
+            let unsaved_changes = documents[current_document].command_handler.is_dirty();
+            TOP_PANEL
+                .with(|panel| {
+                    panel.try_borrow_mut().ok().map(|mut panel| {
+                        panel.discard_document(DiscardingAction::Quit, unsaved_changes)
+                    })
+                })
+                .flatten()
+        } else {
+            command_opt
+        };
+
         if let Some(command) = command_opt {
+            let document = &mut documents[current_document];
             match command {
-                TopPanelCommand::Command(command) => {
-                    top_panel_command_handler.execute(command, &mut fa, &mut states)
+                TopPanelCommand::Command(command) => document.command_handler.execute(
+                    command,
+                    &mut document.model,
+                    &mut document.states,
+                ),
+                TopPanelCommand::Undo => document
+                    .command_handler
+                    .undo(&mut document.model, &mut document.states),
+                TopPanelCommand::Redo => document
+                    .command_handler
+                    .redo(&mut document.model, &mut document.states),
+                TopPanelCommand::Step => {
+                    let configurations = std::mem::take(&mut document.configurations);
+                    document.configurations = document.model.step_all(configurations);
                 }
-                TopPanelCommand::Undo => top_panel_command_handler.undo(&mut fa, &mut states),
-                TopPanelCommand::Redo => top_panel_command_handler.redo(&mut fa, &mut states),
-                TopPanelCommand::Step => configurations = fa.step_all(configurations),
                 TopPanelCommand::StartSimulation(new_configurations) => {
-                    configurations = new_configurations.to_vec()
+                    document.configurations = new_configurations
+                }
+                TopPanelCommand::NewTab => {
+                    open_tab(&mut documents, &mut current_document, Document::default());
+                    switched_tab = true;
+                }
+                TopPanelCommand::SwitchTab(index) => {
+                    documents[current_document].hide();
+                    current_document = index;
+                    documents[current_document].show();
+                    switched_tab = true;
+                }
+                TopPanelCommand::CloseTab => {
+                    documents.remove(current_document);
+                    if documents.is_empty() {
+                        documents.push(Document::default());
+                    }
+                    current_document = current_document.min(documents.len() - 1);
+                    documents[current_document].show();
+                    switched_tab = true;
+                }
+                TopPanelCommand::Quit => {
+                    // The current document has already been saved or discarded,
+                    // so only the others with unsaved changes are left to ask about
+                    documents.remove(current_document);
+                    documents.retain(|document| document.command_handler.is_dirty());
+
+                    // Returning from the main loop closes the window
+                    if documents.is_empty() {
+                        return;
+                    }
+
+                    current_document = 0;
+                    documents[current_document].show();
+                    TOP_PANEL.with(|panel| {
+                        if let Ok(mut panel) = panel.try_borrow_mut() {
+                            panel.discard_document(DiscardingAction::Quit, true);
+                        }
+                    });
+                    switched_tab = true;
                 }
             }
         }
 
+        // Nothing selected or being edited in one document carries over to another
+        if switched_tab {
+            selected_state = None;
+            dragging_selected = false;
+            selected_transition = None;
+            creating_transition_from = None;
+            editing_transition = None;
+            switched_tab = false;
+        }
+
+        // Regular expressions and grammars are edited entirely within the top panel
+        if editing_panel_document {
+            egui_macroquad::draw();
+            next_frame().await;
+            continue;
+        }
+
         set_camera(&Camera2D::from_display_rect(Rect::new(
             -top_panel_width,
             -top_panel_height,
@@ -250,37 +383,44 @@ async fn main() {
             screen_height(),
         )));
 
+        let document = &mut documents[current_document];
+
         // Draw things before egui
         if let Some(selected) = selected_state {
             if dragging_selected {
-                states.insert_position(selected, mouse_position + state_drag_offset);
+                document
+                    .states
+                    .insert_position(selected, mouse_position + state_drag_offset);
             }
         }
 
         // Draw states in order of increasing ID, so higher ID states are drawn on top
-        for state in fa.automaton.states_iter() {
-            let position = *states.get_position(*state);
-
-            // Group transition symbols by the state the transition leads to,
-            // so multiple transitions to the same state will display as stacked symbols
-            let symbols_by_other_state = fa.automaton.transitions_from(*state).into_iter().fold(
-                HashMap::new(),
-                |mut map: HashMap<u32, Vec<String>>, transition| {
-                    map.entry(transition.to())
-                        .or_default()
-                        .push(transition.symbol().to_string());
-                    map
-                },
-            );
+        for state in document.model.states() {
+            let position = *document.states.get_position(state);
+
+            // Group transitions by the state the transition leads to,
+            // so multiple transitions to the same state will display as stacked labels
+            let transitions_by_other_state =
+                document.model.transitions_from(state).into_iter().fold(
+                    HashMap::new(),
+                    |mut map: HashMap<u32, Vec<ModelTransition>>, transition| {
+                        map.entry(transition.to()).or_default().push(transition);
+                        map
+                    },
+                );
 
-            for (other_state, symbols) in symbols_by_other_state {
-                let (rects, angle) = if *state == other_state {
+            for (other_state, transitions) in transitions_by_other_state {
+                let symbols: Vec<String> = transitions
+                    .iter()
+                    .map(|transition| transition.label())
+                    .collect();
+                let (rects, angle) = if state == other_state {
                     draw_self_transition_with_text(&position, &symbols, &font)
-                } else if fa.automaton.states_have_loop(*state, other_state) {
-                    let other_position = states.get_position(other_state);
+                } else if document.model.states_have_loop(state, other_state) {
+                    let other_position = document.states.get_position(other_state);
                     draw_curved_transition_with_text(&position, other_position, &symbols, gl, &font)
                 } else {
-                    let other_position = states.get_position(other_state);
+                    let other_position = document.states.get_position(other_state);
                     draw_transition_with_text(&position, other_position, true, &symbols, gl, &font)
                 };
                 for (i, rect) in rects.iter().enumerate() {
@@ -290,24 +430,29 @@ async fn main() {
                             Mat3::from_rotation_z(-angle)
                                 .transform_vector2(mouse_position - rect.point()),
                         )
-                        && states.point_in_some_state(mouse_position, &fa).is_none()
+                        && document
+                            .states
+                            .point_in_some_state(mouse_position, &document.model)
+                            .is_none()
                     {
-                        selected_transition = Some(FiniteAutomatonTransition::new(
-                            *state,
-                            other_state,
-                            symbols[i].chars().next().unwrap_or(EMPTY_STRING),
-                        ));
+                        selected_transition = Some(transitions[i].clone());
                         selected_state = None;
                     }
                 }
             }
 
             let is_simulating = matches!(top_panel_mode, Mode::Simulate);
-            states.draw_states(&fa, is_simulating, &configurations, selected_state, &font);
+            document.states.draw_states(
+                &document.model,
+                is_simulating,
+                &document.configurations,
+                selected_state,
+                &font,
+            );
         }
 
         if let Some(from) = creating_transition_from {
-            let position = states.get_position(from);
+            let position = document.states.get_position(from);
             if mouse_position.abs_diff_eq(*position, STATE_RADIUS) {
                 draw_self_transition(position);
             } else {
@@ -325,7 +470,7 @@ async fn main() {
                 transition_input_size,
             )
             .titlebar(false)
-            .ui(&mut *root_ui(), |ui| {
+            .ui(&mut root_ui(), |ui| {
                 let id = hash!(editing_transition.2, editing_transition.3);
                 ui.input_text(id, "", &mut editing_transition.1);
                 ui.set_input_focus(id);
@@ -338,16 +483,16 @@ async fn main() {
                 || (is_mouse_button_pressed(MouseButton::Left)
                     && !root_ui().is_mouse_over(screen_mouse_position))
             {
-                let transition = FiniteAutomatonTransition::new(
-                    tuple.2,
-                    tuple.3,
-                    tuple.1.chars().next().unwrap_or(EMPTY_STRING),
-                );
-                top_panel_command_handler.execute(
-                    Command::CreateTransition(transition),
-                    &mut fa,
-                    &mut states,
-                );
+                // Malformed labels are discarded, leaving the transition uncreated
+                if let Some(transition) =
+                    document.model.parse_transition(tuple.2, tuple.3, &tuple.1)
+                {
+                    document.command_handler.execute(
+                        Command::CreateTransition(transition),
+                        &mut document.model,
+                        &mut document.states,
+                    );
+                }
                 editing_transition = None;
             } else if is_key_pressed(KeyCode::Escape) {
                 editing_transition = None;
@@ -361,3 +506,11 @@ async fn main() {
         next_frame().await;
     }
 }
+
+// Open the document in a new tab after the current one, and switch to it
+fn open_tab(documents: &mut Vec<Document>, current_document: &mut usize, document: Document) {
+    documents[*current_document].hide();
+    *current_document += 1;
+    documents.insert(*current_document, document);
+    documents[*current_document].show();
+}
@@ -0,0 +1,724 @@
+use sugarcubes_core::automata::{
+    finite_automaton::{FiniteAutomaton, FiniteAutomatonConfiguration, FiniteAutomatonTransition},
+    mealy_machine::{MealyMachine, MealyMachineConfiguration, MealyMachineTransition},
+    moore_machine::{MooreMachine, MooreMachineConfiguration},
+    pushdown_automaton::{
+        PushdownAutomaton, PushdownAutomatonConfiguration, PushdownAutomatonResult,
+        PushdownAutomatonTransition,
+    },
+    turing_machine::{
+        Acceptance, Direction, TapeAction, TuringMachine, TuringMachineConfiguration,
+        TuringMachineResult, TuringMachineTransition,
+    },
+    Configuration, SimulateAutomaton, Transition, EMPTY_STRING,
+};
+use sugarcubes_core::jff::JffAutomaton;
+
+// Separates the actions on each tape in a multi-tape Turing machine label
+pub const TAPE_SEPARATOR: char = '|';
+// Separates the symbol read by a Mealy machine transition from its output
+pub const OUTPUT_SEPARATOR: char = ';';
+// Separates the symbol read by a pushdown automaton transition from the string it pops,
+// which is separated from the string it pushes by OUTPUT_SEPARATOR
+pub const POP_SEPARATOR: char = ',';
+
+// Run an expression on the generic automaton inside any kind of model
+macro_rules! with_automaton {
+    ($model:expr, $automaton:ident => $body:expr) => {
+        match $model {
+            Model::FiniteAutomaton(fa) => {
+                let $automaton = &fa.automaton;
+                $body
+            }
+            Model::TuringMachine(tm) => {
+                let $automaton = &tm.automaton;
+                $body
+            }
+            Model::MealyMachine(mealy) => {
+                let $automaton = &mealy.automaton;
+                $body
+            }
+            Model::MooreMachine(moore) => {
+                let $automaton = &moore.automaton;
+                $body
+            }
+            Model::PushdownAutomaton(pda) => {
+                let $automaton = &pda.automaton;
+                $body
+            }
+        }
+    };
+    ($model:expr, mut $automaton:ident => $body:expr) => {
+        match $model {
+            Model::FiniteAutomaton(fa) => {
+                let $automaton = &mut fa.automaton;
+                $body
+            }
+            Model::TuringMachine(tm) => {
+                let $automaton = &mut tm.automaton;
+                $body
+            }
+            Model::MealyMachine(mealy) => {
+                let $automaton = &mut mealy.automaton;
+                $body
+            }
+            Model::MooreMachine(moore) => {
+                let $automaton = &mut moore.automaton;
+                $body
+            }
+            Model::PushdownAutomaton(pda) => {
+                let $automaton = &mut pda.automaton;
+                $body
+            }
+        }
+    };
+}
+
+/// An automaton of any type that can be edited and simulated
+pub enum Model {
+    FiniteAutomaton(FiniteAutomaton),
+    TuringMachine(TuringMachine),
+    MealyMachine(MealyMachine),
+    MooreMachine(MooreMachine),
+    PushdownAutomaton(PushdownAutomaton),
+}
+
+/// A transition of any type, belonging to the model of the same type.
+/// Moore machines share the transitions of finite automata.
+#[derive(Clone, PartialEq)]
+pub enum ModelTransition {
+    FiniteAutomaton(FiniteAutomatonTransition),
+    TuringMachine(TuringMachineTransition),
+    MealyMachine(MealyMachineTransition),
+    PushdownAutomaton(PushdownAutomatonTransition),
+}
+
+/// The configurations of a simulation in progress on a model of the same type
+#[derive(Clone)]
+pub enum Configurations {
+    FiniteAutomaton(Vec<FiniteAutomatonConfiguration>),
+    TuringMachine(Vec<TuringMachineConfiguration>),
+    MealyMachine(Vec<MealyMachineConfiguration>),
+    MooreMachine(Vec<MooreMachineConfiguration>),
+    // Along with whether any configurations were dropped after reaching the automaton's limit
+    PushdownAutomaton(Vec<PushdownAutomatonConfiguration>, bool),
+}
+
+/// The outcome of running a model on an input
+#[derive(Copy, Clone, PartialEq)]
+pub enum RunResult {
+    Accepted,
+    Rejected,
+    // Nothing accepted, but the search was cut short, so the input may still be accepted
+    Truncated,
+    // Some branch was still running after the given number of steps
+    DidNotHalt(usize),
+}
+
+/// The outcome of a single configuration, if it has stopped
+#[derive(Copy, Clone, PartialEq)]
+pub enum ConfigurationStatus {
+    Running,
+    Accepted,
+    Rejected,
+}
+
+/// A configuration described independently of the type of its model, for display
+pub struct ConfigurationView {
+    pub state: u32,
+    pub status: ConfigurationStatus,
+    // The remaining input, or the contents of each tape
+    pub lines: Vec<String>,
+    // The output produced so far, for machines with output
+    pub output: Option<String>,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self::FiniteAutomaton(FiniteAutomaton::default())
+    }
+}
+
+impl From<JffAutomaton> for Model {
+    fn from(automaton: JffAutomaton) -> Self {
+        match automaton {
+            JffAutomaton::FiniteAutomaton(fa) => Self::FiniteAutomaton(fa),
+            JffAutomaton::TuringMachine(tm) => Self::TuringMachine(tm),
+            JffAutomaton::MealyMachine(mealy) => Self::MealyMachine(mealy),
+            JffAutomaton::MooreMachine(moore) => Self::MooreMachine(moore),
+            JffAutomaton::PushdownAutomaton(pda) => Self::PushdownAutomaton(pda),
+        }
+    }
+}
+
+impl Model {
+    pub fn as_finite_automaton(&self) -> Option<&FiniteAutomaton> {
+        match self {
+            Self::FiniteAutomaton(fa) => Some(fa),
+            _ => None,
+        }
+    }
+
+    /// Returns the states of the model in order of increasing ID
+    pub fn states(&self) -> Vec<u32> {
+        with_automaton!(self, automaton => automaton.states_iter().cloned().collect())
+    }
+
+    pub fn add_new_state(&mut self) -> u32 {
+        with_automaton!(self, mut automaton => automaton.add_new_state())
+    }
+
+    pub fn try_add_state_with_id(&mut self, id: u32) -> bool {
+        with_automaton!(self, mut automaton => automaton.try_add_state_with_id(id))
+    }
+
+    pub fn remove_state(&mut self, id: u32) {
+        match self {
+            Self::MooreMachine(moore) => moore.set_output(id, ""),
+            Self::TuringMachine(tm) => tm.set_reject(id, false),
+            _ => {}
+        }
+        with_automaton!(self, mut automaton => automaton.remove_state(id))
+    }
+
+    pub fn get_next_state_id(&mut self) -> u32 {
+        with_automaton!(self, mut automaton => automaton.get_next_state_id())
+    }
+
+    pub fn initial(&self) -> Option<u32> {
+        with_automaton!(self, automaton => automaton.initial())
+    }
+
+    pub fn set_initial(&mut self, state: u32) {
+        with_automaton!(self, mut automaton => automaton.set_initial(state))
+    }
+
+    pub fn remove_initial(&mut self) {
+        with_automaton!(self, mut automaton => automaton.remove_initial())
+    }
+
+    pub fn is_final(&self, state: u32) -> bool {
+        with_automaton!(self, automaton => automaton.is_final(state))
+    }
+
+    pub fn set_final(&mut self, state: u32, value: bool) {
+        with_automaton!(self, mut automaton => automaton.set_final(state, value))
+    }
+
+    /// Returns whether a state is a reject state, if the model has reject states
+    pub fn is_reject(&self, state: u32) -> Option<bool> {
+        match self {
+            Self::TuringMachine(tm) => Some(tm.is_reject(state)),
+            _ => None,
+        }
+    }
+
+    pub fn set_reject(&mut self, state: u32, value: bool) {
+        if let Self::TuringMachine(tm) = self {
+            tm.set_reject(state, value);
+        }
+    }
+
+    /// Returns the output of a state, if the model has state outputs
+    pub fn state_output(&self, state: u32) -> Option<String> {
+        match self {
+            Self::MooreMachine(moore) => Some(moore.output(state).to_string()),
+            _ => None,
+        }
+    }
+
+    pub fn set_state_output(&mut self, state: u32, output: &str) {
+        if let Self::MooreMachine(moore) = self {
+            moore.set_output(state, output);
+        }
+    }
+
+    pub fn states_have_loop(&self, state0: u32, state1: u32) -> bool {
+        with_automaton!(self, automaton => automaton.states_have_loop(state0, state1))
+    }
+
+    pub fn transitions(&self) -> Vec<ModelTransition> {
+        match self {
+            Self::FiniteAutomaton(fa) => fa
+                .automaton
+                .transitions()
+                .into_iter()
+                .map(|transition| ModelTransition::FiniteAutomaton(transition.clone()))
+                .collect(),
+            Self::TuringMachine(tm) => tm
+                .automaton
+                .transitions()
+                .into_iter()
+                .map(|transition| ModelTransition::TuringMachine(transition.clone()))
+                .collect(),
+            Self::MealyMachine(mealy) => mealy
+                .automaton
+                .transitions()
+                .into_iter()
+                .map(|transition| ModelTransition::MealyMachine(transition.clone()))
+                .collect(),
+            Self::MooreMachine(moore) => moore
+                .automaton
+                .transitions()
+                .into_iter()
+                .map(|transition| ModelTransition::FiniteAutomaton(transition.clone()))
+                .collect(),
+            Self::PushdownAutomaton(pda) => pda
+                .automaton
+                .transitions()
+                .into_iter()
+                .map(|transition| ModelTransition::PushdownAutomaton(transition.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn transitions_from(&self, from: u32) -> Vec<ModelTransition> {
+        self.transitions()
+            .into_iter()
+            .filter(|transition| transition.from() == from)
+            .collect()
+    }
+
+    pub fn transitions_with(&self, state: u32) -> Vec<ModelTransition> {
+        self.transitions()
+            .into_iter()
+            .filter(|transition| transition.from() == state || transition.to() == state)
+            .collect()
+    }
+
+    /// Adds a transition, ignoring it if it belongs to a different type of model
+    pub fn add_transition(&mut self, transition: ModelTransition) {
+        match (self, transition) {
+            (Self::FiniteAutomaton(fa), ModelTransition::FiniteAutomaton(transition)) => {
+                fa.automaton.add_transition(transition)
+            }
+            (Self::TuringMachine(tm), ModelTransition::TuringMachine(transition)) => {
+                tm.automaton.add_transition(transition)
+            }
+            (Self::MealyMachine(mealy), ModelTransition::MealyMachine(transition)) => {
+                mealy.automaton.add_transition(transition)
+            }
+            (Self::MooreMachine(moore), ModelTransition::FiniteAutomaton(transition)) => {
+                moore.automaton.add_transition(transition)
+            }
+            (Self::PushdownAutomaton(pda), ModelTransition::PushdownAutomaton(transition)) => {
+                pda.automaton.add_transition(transition)
+            }
+            _ => {}
+        }
+    }
+
+    pub fn remove_transition(&mut self, transition: ModelTransition) {
+        match (self, transition) {
+            (Self::FiniteAutomaton(fa), ModelTransition::FiniteAutomaton(transition)) => {
+                fa.automaton.remove_transition(transition)
+            }
+            (Self::TuringMachine(tm), ModelTransition::TuringMachine(transition)) => {
+                tm.automaton.remove_transition(transition)
+            }
+            (Self::MealyMachine(mealy), ModelTransition::MealyMachine(transition)) => {
+                mealy.automaton.remove_transition(transition)
+            }
+            (Self::MooreMachine(moore), ModelTransition::FiniteAutomaton(transition)) => {
+                moore.automaton.remove_transition(transition)
+            }
+            (Self::PushdownAutomaton(pda), ModelTransition::PushdownAutomaton(transition)) => {
+                pda.automaton.remove_transition(transition)
+            }
+            _ => {}
+        }
+    }
+
+    /// Creates a transition of this model's type from the text of its label,
+    /// returning None if the label is malformed
+    pub fn parse_transition(&self, from: u32, to: u32, label: &str) -> Option<ModelTransition> {
+        match self {
+            // Finite automata read the whole label, where ε or an empty label reads nothing
+            Self::FiniteAutomaton(_) => {
+                let read = if label == EMPTY_STRING.to_string() {
+                    ""
+                } else {
+                    label
+                };
+                Some(ModelTransition::FiniteAutomaton(
+                    FiniteAutomatonTransition::with_read(from, to, read),
+                ))
+            }
+            // Moore machines read exactly one symbol on every transition
+            Self::MooreMachine(_) => match single_symbol(label)? {
+                EMPTY_STRING => None,
+                symbol => Some(ModelTransition::FiniteAutomaton(
+                    FiniteAutomatonTransition::new(from, to, symbol),
+                )),
+            },
+            Self::TuringMachine(tm) => {
+                // Each tape's action is written "read;write,direction",
+                // where an empty symbol stands for a blank
+                let actions = label
+                    .split(TAPE_SEPARATOR)
+                    .map(|action| {
+                        let (read, rest) = action.split_once(';')?;
+                        let (write, direction) = rest.split_once(',')?;
+                        let symbol = |text: &str| match text.trim() {
+                            "" => Some(tm.blank_symbol),
+                            text if text.chars().count() == 1 => text.chars().next(),
+                            _ => None,
+                        };
+                        Some(TapeAction::new(
+                            symbol(read)?,
+                            symbol(write)?,
+                            Direction::from_char(direction.trim().chars().next()?)?,
+                        ))
+                    })
+                    .collect::<Option<Vec<TapeAction>>>()?;
+
+                if actions.len() != tm.tape_count {
+                    return None;
+                }
+                Some(ModelTransition::TuringMachine(
+                    TuringMachineTransition::with_actions(from, to, actions),
+                ))
+            }
+            Self::MealyMachine(_) => {
+                // The label is written "symbol;output", where the output may be left out
+                let (symbol, output) = label.split_once(OUTPUT_SEPARATOR).unwrap_or((label, ""));
+                let symbol = symbol.trim();
+                if symbol.chars().count() != 1 {
+                    return None;
+                }
+                Some(ModelTransition::MealyMachine(MealyMachineTransition::new(
+                    from,
+                    to,
+                    symbol.chars().next()?,
+                    output.trim(),
+                )))
+            }
+            Self::PushdownAutomaton(_) => {
+                // The label is written "symbol,pop;push", where an empty or missing part
+                // stands for the empty string
+                let (symbol, rest) = label.split_once(POP_SEPARATOR).unwrap_or((label, ""));
+                let (pop, push) = rest.split_once(OUTPUT_SEPARATOR).unwrap_or((rest, ""));
+                let stack_string = |text: &str| text.trim().replace(EMPTY_STRING, "");
+                Some(ModelTransition::PushdownAutomaton(
+                    PushdownAutomatonTransition::new(
+                        from,
+                        to,
+                        single_symbol(symbol.trim())?,
+                        &stack_string(pop),
+                        &stack_string(push),
+                    ),
+                ))
+            }
+        }
+    }
+
+    pub fn run(&self, input: &str) -> RunResult {
+        let accepted = match self {
+            Self::FiniteAutomaton(fa) => fa.check_input(input),
+            Self::TuringMachine(tm) => {
+                return match tm.run(input) {
+                    TuringMachineResult::Accepted(_) => RunResult::Accepted,
+                    TuringMachineResult::Rejected(_)
+                    | TuringMachineResult::HaltedWithoutAccepting => RunResult::Rejected,
+                    TuringMachineResult::DidNotHalt(steps) => RunResult::DidNotHalt(steps),
+                }
+            }
+            Self::MealyMachine(mealy) => mealy.check_input(input),
+            Self::MooreMachine(moore) => moore.check_input(input),
+            Self::PushdownAutomaton(pda) => {
+                return match pda.run(input) {
+                    PushdownAutomatonResult::Accepted => RunResult::Accepted,
+                    PushdownAutomatonResult::Rejected => RunResult::Rejected,
+                    PushdownAutomatonResult::Truncated => RunResult::Truncated,
+                }
+            }
+        };
+        if accepted {
+            RunResult::Accepted
+        } else {
+            RunResult::Rejected
+        }
+    }
+
+    pub fn initial_configurations(&self, input: &str) -> Configurations {
+        match self {
+            Self::FiniteAutomaton(fa) => {
+                Configurations::FiniteAutomaton(fa.initial_configurations(input))
+            }
+            Self::TuringMachine(tm) => {
+                Configurations::TuringMachine(tm.initial_configurations(input))
+            }
+            Self::MealyMachine(mealy) => {
+                Configurations::MealyMachine(mealy.initial_configurations(input))
+            }
+            Self::MooreMachine(moore) => {
+                Configurations::MooreMachine(moore.initial_configurations(input))
+            }
+            Self::PushdownAutomaton(pda) => {
+                Configurations::PushdownAutomaton(pda.initial_configurations(input), false)
+            }
+        }
+    }
+
+    /// Steps every configuration, leaving configurations for a different type of model as they are
+    pub fn step_all(&self, configurations: Configurations) -> Configurations {
+        match (self, configurations) {
+            (Self::FiniteAutomaton(fa), Configurations::FiniteAutomaton(configurations)) => {
+                Configurations::FiniteAutomaton(fa.step_all(configurations))
+            }
+            (Self::TuringMachine(tm), Configurations::TuringMachine(configurations)) => {
+                Configurations::TuringMachine(tm.step_all(configurations))
+            }
+            (Self::MealyMachine(mealy), Configurations::MealyMachine(configurations)) => {
+                Configurations::MealyMachine(mealy.step_all(configurations))
+            }
+            (Self::MooreMachine(moore), Configurations::MooreMachine(configurations)) => {
+                Configurations::MooreMachine(moore.step_all(configurations))
+            }
+            (
+                Self::PushdownAutomaton(pda),
+                Configurations::PushdownAutomaton(configurations, truncated),
+            ) => {
+                let (configurations, dropped) = pda.step_all_bounded(configurations);
+                Configurations::PushdownAutomaton(configurations, truncated || dropped)
+            }
+            (_, configurations) => configurations,
+        }
+    }
+}
+
+// Reads a label holding at most one symbol, where an empty label stands for the empty string
+fn single_symbol(label: &str) -> Option<char> {
+    let mut chars = label.chars();
+    match (chars.next(), chars.next()) {
+        (None, _) => Some(EMPTY_STRING),
+        (Some(symbol), None) => Some(symbol),
+        _ => None,
+    }
+}
+
+impl ModelTransition {
+    pub fn from(&self) -> u32 {
+        match self {
+            Self::FiniteAutomaton(transition) => transition.from(),
+            Self::TuringMachine(transition) => transition.from(),
+            Self::MealyMachine(transition) => transition.from(),
+            Self::PushdownAutomaton(transition) => transition.from(),
+        }
+    }
+
+    pub fn to(&self) -> u32 {
+        match self {
+            Self::FiniteAutomaton(transition) => transition.to(),
+            Self::TuringMachine(transition) => transition.to(),
+            Self::MealyMachine(transition) => transition.to(),
+            Self::PushdownAutomaton(transition) => transition.to(),
+        }
+    }
+
+    /// Returns the text displayed on the transition, in the format read by `Model::parse_transition`
+    pub fn label(&self) -> String {
+        match self {
+            Self::FiniteAutomaton(transition) if transition.read().is_empty() => {
+                EMPTY_STRING.to_string()
+            }
+            Self::FiniteAutomaton(transition) => transition.read().to_string(),
+            Self::TuringMachine(transition) => transition
+                .actions()
+                .iter()
+                .map(|action| {
+                    format!(
+                        "{};{},{}",
+                        action.read,
+                        action.write,
+                        action.direction.to_char()
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(&TAPE_SEPARATOR.to_string()),
+            Self::MealyMachine(transition) => format!(
+                "{}{}{}",
+                transition.symbol(),
+                OUTPUT_SEPARATOR,
+                transition.output()
+            ),
+            Self::PushdownAutomaton(transition) => {
+                let stack_string = |text: &str| {
+                    if text.is_empty() {
+                        EMPTY_STRING.to_string()
+                    } else {
+                        text.to_string()
+                    }
+                };
+                format!(
+                    "{}{}{}{}{}",
+                    transition.symbol(),
+                    POP_SEPARATOR,
+                    stack_string(transition.pop()),
+                    OUTPUT_SEPARATOR,
+                    stack_string(transition.push())
+                )
+            }
+        }
+    }
+}
+
+impl Default for Configurations {
+    fn default() -> Self {
+        Self::FiniteAutomaton(Vec::new())
+    }
+}
+
+impl Configurations {
+    /// Returns the current state of each configuration
+    pub fn states(&self) -> Vec<u32> {
+        match self {
+            Self::FiniteAutomaton(configurations) => configurations
+                .iter()
+                .map(|configuration| configuration.state())
+                .collect(),
+            Self::TuringMachine(configurations) => configurations
+                .iter()
+                .map(|configuration| configuration.state())
+                .collect(),
+            Self::MealyMachine(configurations) => configurations
+                .iter()
+                .map(|configuration| configuration.state())
+                .collect(),
+            Self::MooreMachine(configurations) => configurations
+                .iter()
+                .map(|configuration| configuration.state())
+                .collect(),
+            Self::PushdownAutomaton(configurations, _) => configurations
+                .iter()
+                .map(|configuration| configuration.state())
+                .collect(),
+        }
+    }
+
+    /// Whether configurations have been dropped during the simulation to stay within
+    /// the model's limit, so that rejecting is not conclusive
+    pub fn is_truncated(&self) -> bool {
+        matches!(self, Self::PushdownAutomaton(_, true))
+    }
+
+    /// Describes each configuration, and whether it has accepted or rejected
+    pub fn views(&self, model: &Model) -> Vec<ConfigurationView> {
+        match (self, model) {
+            (Self::FiniteAutomaton(configurations), Model::FiniteAutomaton(fa)) => configurations
+                .iter()
+                .map(|configuration| {
+                    let exhausted = configuration.remaining_string.is_empty()
+                        && !fa
+                            .automaton
+                            .transitions_from(configuration.state())
+                            .into_iter()
+                            .any(|transition| transition.read().is_empty());
+                    let status = if !exhausted {
+                        ConfigurationStatus::Running
+                    } else if fa.automaton.is_final(configuration.state()) {
+                        ConfigurationStatus::Accepted
+                    } else {
+                        ConfigurationStatus::Rejected
+                    };
+                    ConfigurationView {
+                        state: configuration.state(),
+                        status,
+                        lines: vec![configuration.remaining_string.clone()],
+                        output: None,
+                    }
+                })
+                .collect(),
+            (Self::TuringMachine(configurations), Model::TuringMachine(tm)) => configurations
+                .iter()
+                .map(|configuration| {
+                    let halted = tm.step(configuration.clone()).is_empty();
+                    let status = if tm.is_reject(configuration.state()) {
+                        ConfigurationStatus::Rejected
+                    } else if tm.acceptance == Acceptance::FinalState
+                        && tm.automaton.is_final(configuration.state())
+                    {
+                        ConfigurationStatus::Accepted
+                    } else if !halted {
+                        ConfigurationStatus::Running
+                    } else if tm.acceptance == Acceptance::Halting {
+                        ConfigurationStatus::Accepted
+                    } else {
+                        ConfigurationStatus::Rejected
+                    };
+                    ConfigurationView {
+                        state: configuration.state(),
+                        status,
+                        lines: configuration
+                            .tapes
+                            .iter()
+                            .map(|tape| tape.to_string())
+                            .collect(),
+                        output: None,
+                    }
+                })
+                .collect(),
+            (Self::MealyMachine(configurations), Model::MealyMachine(mealy)) => configurations
+                .iter()
+                .map(|configuration| {
+                    let status = if configuration.remaining_string.is_empty() {
+                        ConfigurationStatus::Accepted
+                    } else if mealy.step(configuration.clone()).is_empty() {
+                        ConfigurationStatus::Rejected
+                    } else {
+                        ConfigurationStatus::Running
+                    };
+                    ConfigurationView {
+                        state: configuration.state(),
+                        status,
+                        lines: vec![configuration.remaining_string.clone()],
+                        output: Some(configuration.output.clone()),
+                    }
+                })
+                .collect(),
+            (Self::MooreMachine(configurations), Model::MooreMachine(moore)) => configurations
+                .iter()
+                .map(|configuration| {
+                    let status = if configuration.remaining_string.is_empty() {
+                        ConfigurationStatus::Accepted
+                    } else if moore.step(configuration.clone()).is_empty() {
+                        ConfigurationStatus::Rejected
+                    } else {
+                        ConfigurationStatus::Running
+                    };
+                    ConfigurationView {
+                        state: configuration.state(),
+                        status,
+                        lines: vec![configuration.remaining_string.clone()],
+                        output: Some(configuration.output.clone()),
+                    }
+                })
+                .collect(),
+            (Self::PushdownAutomaton(configurations, _), Model::PushdownAutomaton(pda)) => {
+                configurations
+                    .iter()
+                    .map(|configuration| {
+                        let status = if pda.is_accepting(configuration) {
+                            ConfigurationStatus::Accepted
+                        } else if pda.step(configuration.clone()).is_empty() {
+                            ConfigurationStatus::Rejected
+                        } else {
+                            ConfigurationStatus::Running
+                        };
+                        // The second line shows the stack, with its top first
+                        ConfigurationView {
+                            state: configuration.state(),
+                            status,
+                            lines: vec![
+                                configuration.remaining_string.clone(),
+                                configuration.stack.clone(),
+                            ],
+                            output: None,
+                        }
+                    })
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
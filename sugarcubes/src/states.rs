@@ -1,12 +1,7 @@
-use crate::transitions::*;
-
-use sugarcubes_core::automata::{
-    finite_automaton::{FiniteAutomaton, FiniteAutomatonConfiguration},
-    Configuration,
-};
+use crate::{model::*, transitions::*};
 
 use macroquad::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 pub const INACTIVE_COLOR: Color = Color::new(0.90, 0.93, 0.52, 1.00);
 pub const ACTIVE_COLOR: Color = Color::new(0.44, 0.45, 0.19, 1.00);
@@ -17,8 +12,15 @@ pub const STATE_SIDES: u8 = 35;
 pub const FINAL_STATE_CIRCLE_RATIO: f32 = 0.85;
 pub const INITIAL_ARROW_SIZE: f32 = 24.;
 pub const STATE_FONT_SIZE: f64 = 30.;
+pub const OUTPUT_FONT_SIZE: f64 = 24.;
+pub const OUTPUT_PADDING: f32 = 4.;
 
 pub const DEFAULT_NAME_PREFIX: &str = "q";
+
+// The position of the first automatically placed state,
+// and the spacing between automatically placed states
+pub const AUTO_PLACE_ORIGIN: Vec2 = const_vec2!([150., 150.]);
+pub const AUTO_PLACE_SPACING: Vec2 = const_vec2!([200., 150.]);
 // Macroquad currently draws text at weird Y coordinates,
 // this is a sane adjustment value.
 pub const STATE_TEXT_Y_ADJUSTMENT: f32 = 4.;
@@ -59,20 +61,15 @@ impl States {
         DEFAULT_NAME_PREFIX.to_owned() + &state.to_string()
     }
 
-    pub fn add_state(&mut self, fa: &mut FiniteAutomaton, position: Vec2) -> u32 {
-        let state = fa.automaton.add_new_state();
+    pub fn add_state(&mut self, model: &mut Model, position: Vec2) -> u32 {
+        let state = model.add_new_state();
         self.position_map.insert(state, position);
         self.name_map.insert(state, Self::default_name(state));
         state
     }
 
-    pub fn try_add_state_with_id(
-        &mut self,
-        fa: &mut FiniteAutomaton,
-        position: Vec2,
-        id: u32,
-    ) -> bool {
-        let succeeded = fa.automaton.try_add_state_with_id(id);
+    pub fn try_add_state_with_id(&mut self, model: &mut Model, position: Vec2, id: u32) -> bool {
+        let succeeded = model.try_add_state_with_id(id);
         if succeeded {
             self.position_map.insert(id, position);
             self.name_map
@@ -81,8 +78,61 @@ impl States {
         succeeded
     }
 
-    pub fn remove_state(&mut self, fa: &mut FiniteAutomaton, state: u32) {
-        fa.automaton.remove_state(state);
+    /// Create positions and default names for every state of an automaton,
+    /// placing states in columns by their distance from the initial state
+    pub fn auto_place(model: &Model) -> Self {
+        let mut states = Self::new();
+
+        // Breadth-first search from the initial state, grouping states into layers
+        let mut layers: Vec<Vec<u32>> = Vec::new();
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        if let Some(initial) = model.initial() {
+            visited.insert(initial);
+            queue.push_back((initial, 0));
+        }
+        while let Some((state, depth)) = queue.pop_front() {
+            if layers.len() <= depth {
+                layers.push(Vec::new());
+            }
+            layers[depth].push(state);
+
+            let mut next_states: Vec<u32> = model
+                .transitions_from(state)
+                .into_iter()
+                .map(|transition| transition.to())
+                .collect();
+            next_states.sort_unstable();
+            for next in next_states {
+                if visited.insert(next) {
+                    queue.push_back((next, depth + 1));
+                }
+            }
+        }
+
+        // Unreachable states go in a final column
+        let unreachable: Vec<u32> = model
+            .states()
+            .into_iter()
+            .filter(|state| !visited.contains(state))
+            .collect();
+        if !unreachable.is_empty() {
+            layers.push(unreachable);
+        }
+
+        for (column, layer) in layers.iter().enumerate() {
+            for (row, &state) in layer.iter().enumerate() {
+                let offset = vec2(column as f32, row as f32) * AUTO_PLACE_SPACING;
+                states.insert_position(state, AUTO_PLACE_ORIGIN + offset);
+                states.insert_name(state, Self::default_name(state));
+            }
+        }
+
+        states
+    }
+
+    pub fn remove_state(&mut self, model: &mut Model, state: u32) {
+        model.remove_state(state);
         self.position_map.remove(&state);
         self.name_map.remove(&state);
     }
@@ -92,11 +142,11 @@ impl States {
         point.abs_diff_eq(position, STATE_RADIUS)
     }
 
-    pub fn point_in_some_state(&self, point: Vec2, fa: &FiniteAutomaton) -> Option<u32> {
+    pub fn point_in_some_state(&self, point: Vec2, model: &Model) -> Option<u32> {
         // Iterate in reverse, so the highest-numbered state is selected first
-        fa.automaton
-            .states_iter()
-            .cloned()
+        model
+            .states()
+            .into_iter()
             .rev()
             .find(|&state| self.point_in_state(point, state))
     }
@@ -165,28 +215,54 @@ impl States {
         );
     }
 
+    /// Draw the output of a state in a box above its top right, as in JFLAP
+    pub fn draw_state_output(&mut self, state: u32, output: &str, font: &Font) {
+        let position = *self.get_position(state);
+        let text_size = measure_text(output, Some(*font), OUTPUT_FONT_SIZE as _, 1.0);
+        let corner = position + vec2(STATE_RADIUS / 2., -STATE_RADIUS);
+        let size = vec2(
+            text_size.width + OUTPUT_PADDING * 2.,
+            OUTPUT_FONT_SIZE as f32 / 2. + OUTPUT_PADDING * 2.,
+        );
+        draw_rectangle(corner.x, corner.y - size.y, size.x, size.y, WHITE);
+        draw_rectangle_lines(corner.x, corner.y - size.y, size.x, size.y, 2., BLACK);
+        draw_text_ex(
+            output,
+            corner.x + OUTPUT_PADDING,
+            corner.y - OUTPUT_PADDING,
+            TextParams {
+                font_size: OUTPUT_FONT_SIZE as _,
+                font: *font,
+                color: BLACK,
+                ..Default::default()
+            },
+        );
+    }
+
     pub fn draw_states(
         &mut self,
-        fa: &FiniteAutomaton,
+        model: &Model,
         is_simulating: bool,
-        configurations: &[FiniteAutomatonConfiguration],
+        configurations: &Configurations,
         selected_state: Option<u32>,
         font: &Font,
     ) {
         // Draw states in order of increasing ID, so higher ID states are drawn on top
-        for &state in fa.automaton.states_iter() {
-            let is_active = is_simulating
-                && configurations
-                    .iter()
-                    .any(|configuration| configuration.state() == state);
+        let active_states = configurations.states();
+        for state in model.states() {
+            let is_active = is_simulating && active_states.contains(&state);
             self.draw_state(
                 state,
                 is_active,
-                fa.automaton.initial() == Some(state),
-                fa.automaton.is_final(state),
+                model.initial() == Some(state),
+                model.is_final(state),
                 selected_state == Some(state),
                 font,
-            )
+            );
+
+            if let Some(output) = model.state_output(state) {
+                self.draw_state_output(state, &output, font);
+            }
         }
     }
 }
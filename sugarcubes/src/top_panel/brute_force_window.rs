@@ -0,0 +1,86 @@
+use super::{PanelDocument, TopPanel, ACCEPT_COLOR, REJECT_COLOR};
+use sugarcubes_core::grammar::{DerivationResult, DERIVES_SYMBOL, EMPTY_STRING_SYMBOL};
+
+impl TopPanel {
+    // Search for a derivation of a string in any grammar, within the chosen limits
+    pub(super) fn show_brute_force_window(&mut self, egui_ctx: &egui::CtxRef) {
+        let grammar = match &self.panel_document {
+            Some(PanelDocument::Grammar(grammar)) => grammar,
+            _ => return,
+        };
+        let input = &mut self.brute_force_input;
+        let limits = &mut self.brute_force_limits;
+        let result = &mut self.brute_force_result;
+
+        let mut window_open = true;
+        let response = egui::Window::new("Brute Force Parse")
+            .open(&mut window_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                egui::Grid::new("brute_force_options").show(ui, |ui| {
+                    ui.label("Input:");
+                    if ui.text_edit_singleline(input).changed() {
+                        *result = None;
+                    }
+                    ui.end_row();
+
+                    ui.label("Depth limit:");
+                    ui.add(egui::DragValue::new(&mut limits.max_depth).clamp_range(1..=1000));
+                    ui.end_row();
+
+                    ui.label("Node limit:");
+                    ui.add(
+                        egui::DragValue::new(&mut limits.max_nodes)
+                            .clamp_range(1..=10_000_000)
+                            .speed(100),
+                    );
+                    ui.end_row();
+                });
+
+                if ui.button("Parse").clicked() {
+                    *result = Some(grammar.brute_force_derivation(input, *limits));
+                }
+
+                match result {
+                    Some(DerivationResult::Derived(derivation)) => {
+                        ui.add(egui::Label::new("Accepted").text_color(ACCEPT_COLOR));
+                        egui::ScrollArea::auto_sized().show(ui, |ui| {
+                            for (i, form) in derivation.iter().enumerate() {
+                                let form = if form.is_empty() {
+                                    EMPTY_STRING_SYMBOL.to_string()
+                                } else {
+                                    form.clone()
+                                };
+                                let text = if i == 0 {
+                                    form
+                                } else {
+                                    format!("{} {}", DERIVES_SYMBOL, form)
+                                };
+                                ui.add(egui::Label::new(text).monospace());
+                            }
+                        });
+                    }
+                    Some(DerivationResult::NotDerivable) => {
+                        ui.add(egui::Label::new("Rejected").text_color(REJECT_COLOR));
+                    }
+                    Some(DerivationResult::LimitReached) => {
+                        ui.add(
+                            egui::Label::new("Limit reached without finding a derivation")
+                                .text_color(REJECT_COLOR),
+                        );
+                    }
+                    None => {}
+                }
+            });
+
+        if let Some(inner_response) = response {
+            self.contains_mouse |= inner_response.response.hovered();
+        }
+
+        if !window_open {
+            self.brute_force_open = false;
+            self.brute_force_result = None;
+        }
+    }
+}
@@ -1,6 +1,5 @@
 use super::{Command, TopPanel};
-use crate::states::States;
-use sugarcubes_core::automata::finite_automaton::{FiniteAutomaton, FiniteAutomatonTransition};
+use crate::{model::*, states::States};
 
 use macroquad::prelude::*;
 
@@ -8,11 +7,11 @@ impl TopPanel {
     pub(super) fn context_menu(
         &mut self,
         egui_ctx: &egui::CtxRef,
-        fa: &FiniteAutomaton,
+        model: &Model,
         states: &mut States,
         mouse_position: &Vec2,
         selected_state: &mut Option<u32>,
-        selected_transition: &mut Option<FiniteAutomatonTransition>,
+        selected_transition: &mut Option<ModelTransition>,
     ) -> Option<Command> {
         let mut command = None;
 
@@ -26,7 +25,7 @@ impl TopPanel {
                 let (context_menu_command, mouse_in_popup) = self.show_context_menu(
                     ui,
                     popup_id,
-                    fa,
+                    model,
                     states,
                     selected_state,
                     selected_transition,
@@ -63,10 +62,10 @@ impl TopPanel {
         &mut self,
         ui: &mut egui::Ui,
         popup_id: egui::Id,
-        fa: &FiniteAutomaton,
+        model: &Model,
         states: &mut States,
         selected_state: &mut Option<u32>,
-        selected_transition: &mut Option<FiniteAutomatonTransition>,
+        selected_transition: &mut Option<ModelTransition>,
     ) -> (Option<Command>, bool) {
         let mut command = None;
         let mut mouse_in_popup = false;
@@ -83,11 +82,10 @@ impl TopPanel {
                     ui.with_layout(egui::Layout::top_down_justified(egui::Align::LEFT), |ui| {
                         ui.set_width(100.0 - 2.0 * frame_margin.x);
                         if let Some(selected) = *selected_state {
-                            let mut is_initial = fa.automaton.initial() == Some(selected);
+                            let mut is_initial = model.initial() == Some(selected);
                             if ui.checkbox(&mut is_initial, "Initial").changed() {
                                 if is_initial {
-                                    command =
-                                        Some(Command::SetInitial(selected, fa.automaton.initial()));
+                                    command = Some(Command::SetInitial(selected, model.initial()));
                                 } else {
                                     command = Some(Command::RemoveInitial(selected));
                                 }
@@ -95,13 +93,21 @@ impl TopPanel {
                                 ui.memory().close_popup();
                             }
 
-                            let mut is_final = fa.automaton.is_final(selected);
+                            let mut is_final = model.is_final(selected);
                             if ui.checkbox(&mut is_final, "Final").changed() {
                                 command = Some(Command::SetFinal(selected, is_final));
                                 *selected_state = None;
                                 ui.memory().close_popup();
                             }
 
+                            if let Some(mut is_reject) = model.is_reject(selected) {
+                                if ui.checkbox(&mut is_reject, "Reject").changed() {
+                                    command = Some(Command::SetReject(selected, is_reject));
+                                    *selected_state = None;
+                                    ui.memory().close_popup();
+                                }
+                            }
+
                             ui.separator();
 
                             if ui.button("Set Name").clicked() {
@@ -113,6 +119,17 @@ impl TopPanel {
                                 ui.memory().close_popup();
                             }
 
+                            if let Some(output) = model.state_output(selected) {
+                                if ui.button("Set Output").clicked() {
+                                    self.set_output_input_window.open = true;
+                                    self.set_output_input_window.input = output;
+                                    self.set_output_state_id = Some(selected);
+                                    self.set_output_input_window.end_of_line = true;
+                                    *selected_state = None;
+                                    ui.memory().close_popup();
+                                }
+                            }
+
                             ui.separator();
 
                             if ui.button("Delete").clicked() {
@@ -120,16 +137,13 @@ impl TopPanel {
                                     selected,
                                     *states.get_position(selected),
                                     states.get_name(selected),
-                                    fa.automaton
-                                        .transitions_with(selected)
-                                        .into_iter()
-                                        .cloned()
-                                        .collect(),
+                                    model.state_output(selected),
+                                    model.transitions_with(selected),
                                 ));
                                 *selected_state = None;
                                 ui.memory().close_popup();
                             }
-                        } else if let Some(selected) = *selected_transition {
+                        } else if let Some(selected) = selected_transition.clone() {
                             if ui.button("Delete").clicked() {
                                 command = Some(Command::DeleteTransition(selected));
                                 *selected_transition = None;
@@ -0,0 +1,25 @@
+use super::{TopPanel, REJECT_COLOR};
+use sugarcubes_core::grammar::TransformError;
+
+impl TopPanel {
+    pub(super) fn show_conversion_error_window(
+        &mut self,
+        egui_ctx: &egui::CtxRef,
+        conversion_error: TransformError,
+    ) {
+        let mut error_open = true;
+        egui::Window::new("Conversion Failed")
+            .open(&mut error_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                ui.add(
+                    egui::widgets::Label::new(conversion_error.to_string())
+                        .text_color(REJECT_COLOR),
+                );
+            });
+        if !error_open {
+            self.conversion_error = None;
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use super::{PanelDocument, TopPanel, ACCEPT_COLOR, REJECT_COLOR};
+use sugarcubes_core::grammar::CykTable;
+
+impl TopPanel {
+    pub(super) fn show_cyk_input_window(&mut self, egui_ctx: &egui::CtxRef) {
+        let (hit_ok, contains_mouse) = self.cyk_input_window.show(egui_ctx);
+        self.contains_mouse |= contains_mouse;
+
+        if hit_ok {
+            self.cyk_input_window.open = false;
+            if let Some(PanelDocument::Grammar(grammar)) = &self.panel_document {
+                self.cyk_table = CykTable::new(grammar, &self.cyk_input_window.input);
+                self.parse_tree = None;
+            }
+        }
+
+        if !self.cyk_input_window.open {
+            self.cyk_input_window.input.clear();
+        }
+    }
+
+    pub(super) fn show_cyk_window(&mut self, egui_ctx: &egui::CtxRef) {
+        let table = match &mut self.cyk_table {
+            Some(table) => table,
+            None => return,
+        };
+        let parse_tree = &mut self.parse_tree;
+
+        let mut window_open = true;
+        let response = egui::Window::new("CYK Parse")
+            .open(&mut window_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                let input = table.input().to_vec();
+                ui.label(format!(
+                    "Variables deriving each substring of \"{}\", by length",
+                    input.iter().collect::<String>()
+                ));
+                ui.separator();
+
+                // Each row holds the substrings of one length, starting at each column
+                egui::Grid::new("cyk_table").striped(true).show(ui, |ui| {
+                    ui.label("");
+                    for symbol in &input {
+                        ui.add(egui::Label::new(symbol.to_string()).monospace().strong());
+                    }
+                    ui.end_row();
+
+                    for length in 1..=input.len() {
+                        ui.label(length.to_string());
+                        for start in 0..=input.len() - length {
+                            let text = if !table.is_filled(start, length) {
+                                String::new()
+                            } else if table.cell(start, length).is_empty() {
+                                "∅".to_string()
+                            } else {
+                                table
+                                    .cell(start, length)
+                                    .iter()
+                                    .map(char::to_string)
+                                    .collect::<Vec<String>>()
+                                    .join(",")
+                            };
+                            ui.add(egui::Label::new(text).monospace());
+                        }
+                        ui.end_row();
+                    }
+                });
+
+                ui.separator();
+
+                if table.is_complete() {
+                    if table.accepts() {
+                        ui.add(egui::Label::new("Accepted").text_color(ACCEPT_COLOR));
+                        if ui.button("Show Parse Tree").clicked() {
+                            *parse_tree = table.parse_tree();
+                        }
+                    } else {
+                        ui.add(egui::Label::new("Rejected").text_color(REJECT_COLOR));
+                    }
+                } else {
+                    ui.horizontal(|ui| {
+                        if ui.button("Step").clicked() {
+                            table.fill_next_cell();
+                        }
+                        if ui.button("Fill All").clicked() {
+                            table.fill();
+                        }
+                    });
+                }
+            });
+
+        if let Some(inner_response) = response {
+            self.contains_mouse |= inner_response.response.hovered();
+        }
+
+        if !window_open {
+            self.cyk_table = None;
+        }
+    }
+}
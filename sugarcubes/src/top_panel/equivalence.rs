@@ -0,0 +1,86 @@
+use super::{TopPanel, ACCEPT_COLOR, REJECT_COLOR};
+use sugarcubes_core::automata::{finite_automaton::Equivalence, EMPTY_STRING};
+
+// Which of the documents being compared is not a finite automaton
+#[derive(Copy, Clone)]
+pub enum ComparisonError {
+    // The open document
+    ThisDocument,
+    // The document in the chosen file
+    OtherDocument,
+}
+
+impl TopPanel {
+    pub(super) fn show_equivalence_result_window(
+        &mut self,
+        egui_ctx: &egui::CtxRef,
+        equivalence_result: Equivalence,
+    ) {
+        let mut result_open = true;
+        egui::Window::new("Equivalence Result")
+            .open(&mut result_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| match equivalence_result {
+                Equivalence::Equivalent => {
+                    ui.add(
+                        egui::widgets::Label::new("The automata are equivalent")
+                            .text_color(ACCEPT_COLOR),
+                    );
+                }
+                Equivalence::Counterexample {
+                    string,
+                    accepted_by_first,
+                } => {
+                    ui.add(
+                        egui::widgets::Label::new("The automata are not equivalent")
+                            .text_color(REJECT_COLOR),
+                    );
+
+                    let string = if string.is_empty() {
+                        EMPTY_STRING.to_string()
+                    } else {
+                        string
+                    };
+                    let (accepting, rejecting) = if accepted_by_first {
+                        ("this automaton", "the other automaton")
+                    } else {
+                        ("the other automaton", "this automaton")
+                    };
+                    ui.label(format!(
+                        "\"{}\" is accepted by {}, but rejected by {}",
+                        string, accepting, rejecting
+                    ));
+                }
+            });
+        if !result_open {
+            self.equivalence_result = None;
+        }
+    }
+
+    pub(super) fn show_comparison_error_window(
+        &mut self,
+        egui_ctx: &egui::CtxRef,
+        comparison_error: ComparisonError,
+    ) {
+        let message = match comparison_error {
+            ComparisonError::ThisDocument => {
+                "This document is not a finite automaton, so it cannot be compared"
+            }
+            ComparisonError::OtherDocument => {
+                "The chosen file does not hold a finite automaton, so it cannot be compared"
+            }
+        };
+        let mut error_open = true;
+        egui::Window::new("Could Not Compare")
+            .open(&mut error_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                ui.add(egui::widgets::Label::new(message).text_color(REJECT_COLOR));
+            });
+        if !error_open {
+            self.comparison_error = None;
+        }
+    }
+}
@@ -1,19 +1,15 @@
 use super::{TopPanel, ACCEPT_COLOR, REJECT_COLOR};
-use sugarcubes_core::automata::{finite_automaton::FiniteAutomaton, SimulateAutomaton};
+use crate::model::{Model, RunResult};
 
 impl TopPanel {
-    pub(super) fn show_fast_run_input_window(
-        &mut self,
-        egui_ctx: &egui::CtxRef,
-        fa: &FiniteAutomaton,
-    ) {
+    pub(super) fn show_fast_run_input_window(&mut self, egui_ctx: &egui::CtxRef, model: &Model) {
         let (hit_ok, contains_mouse) = self.fast_run_input_window.show(egui_ctx);
         self.contains_mouse |= contains_mouse;
 
         if hit_ok {
             self.fast_run_input_window.open = false;
             self.fast_run_string = self.fast_run_input_window.input.clone();
-            self.fast_run_result = Some(fa.check_input(&self.fast_run_input_window.input));
+            self.fast_run_result = Some(model.run(&self.fast_run_input_window.input));
         }
 
         if !self.fast_run_input_window.open {
@@ -24,7 +20,7 @@ impl TopPanel {
     pub(super) fn show_fast_run_result_window(
         &mut self,
         egui_ctx: &egui::CtxRef,
-        fast_run_result: bool,
+        fast_run_result: RunResult,
     ) {
         let mut result_open = true;
         egui::Window::new("Fast Run Result")
@@ -34,10 +30,19 @@ impl TopPanel {
             .show(egui_ctx, |ui| {
                 ui.horizontal(|ui| {
                     ui.label(format!("Result for string \"{}\": ", self.fast_run_string));
-                    if fast_run_result {
-                        ui.add(egui::widgets::Label::new("Accepted").text_color(ACCEPT_COLOR));
-                    } else {
-                        ui.add(egui::widgets::Label::new("Rejected").text_color(REJECT_COLOR));
+                    match fast_run_result {
+                        RunResult::Accepted => {
+                            ui.add(egui::widgets::Label::new("Accepted").text_color(ACCEPT_COLOR));
+                        }
+                        RunResult::Rejected => {
+                            ui.add(egui::widgets::Label::new("Rejected").text_color(REJECT_COLOR));
+                        }
+                        RunResult::Truncated => {
+                            ui.label("Not accepted before reaching the configuration limit");
+                        }
+                        RunResult::DidNotHalt(steps) => {
+                            ui.label(format!("Did not halt within {} steps", steps));
+                        }
                     }
                 });
             });
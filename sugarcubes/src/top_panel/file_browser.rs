@@ -0,0 +1,226 @@
+use super::{TopPanel, REJECT_COLOR};
+use crate::{DocumentCommand, DOCUMENT_COMMAND_BUFFER};
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const LIST_HEIGHT: f32 = 300.;
+const JFF_EXTENSION: &str = "jff";
+
+/// What the user is choosing a file for
+#[derive(Copy, Clone, PartialEq)]
+pub enum FileBrowserPurpose {
+    Open,
+    Compare,
+    SaveAs,
+}
+
+// A window for choosing a JFF file on the desktop, where there is no browser file picker.
+// The directory is remembered between uses.
+pub struct FileBrowser {
+    pub purpose: Option<FileBrowserPurpose>,
+    directory: PathBuf,
+    // The subdirectories and JFF files of the directory, with whether each is a directory
+    entries: Vec<(String, bool)>,
+    file_name: String,
+    pub error: Option<String>,
+}
+
+impl FileBrowser {
+    pub fn new() -> Self {
+        Self {
+            purpose: None,
+            directory: std::env::current_dir().unwrap_or_default(),
+            entries: Vec::new(),
+            file_name: String::new(),
+            error: None,
+        }
+    }
+
+    /// Opens the browser, starting in the directory of the document's file if it has one
+    pub fn open(&mut self, purpose: FileBrowserPurpose, document_path: Option<&Path>) {
+        self.file_name.clear();
+        if let Some(path) = document_path {
+            if let Some(directory) = path.parent().filter(|parent| parent.is_dir()) {
+                self.directory = directory.to_path_buf();
+            }
+            if purpose == FileBrowserPurpose::SaveAs {
+                if let Some(file_name) = path.file_name() {
+                    self.file_name = file_name.to_string_lossy().into_owned();
+                }
+            }
+        }
+        self.purpose = Some(purpose);
+        self.error = None;
+        self.read_directory();
+    }
+
+    // List the directory's contents, with subdirectories first
+    fn read_directory(&mut self) {
+        self.entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    let is_directory = path.is_dir();
+                    let is_jff = path.extension().is_some_and(|ext| ext == JFF_EXTENSION);
+                    if is_directory || is_jff {
+                        Some((
+                            entry.file_name().to_string_lossy().into_owned(),
+                            is_directory,
+                        ))
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+            Err(error) => {
+                self.error = Some(error.to_string());
+                Vec::new()
+            }
+        };
+        self.entries
+            .sort_by(|(name, is_directory), (other_name, other_is_directory)| {
+                other_is_directory
+                    .cmp(is_directory)
+                    .then_with(|| name.to_lowercase().cmp(&other_name.to_lowercase()))
+            });
+    }
+
+    /// Shows the browser, returning the chosen file's path once the user confirms it,
+    /// and whether the window contains the mouse
+    pub fn show(&mut self, egui_ctx: &egui::CtxRef) -> (Option<PathBuf>, bool) {
+        let purpose = match self.purpose {
+            Some(purpose) => purpose,
+            None => return (None, false),
+        };
+        let (title, confirm_label) = match purpose {
+            FileBrowserPurpose::Open => ("Open", "Open"),
+            FileBrowserPurpose::Compare => ("Compare With", "Open"),
+            FileBrowserPurpose::SaveAs => ("Save As", "Save"),
+        };
+
+        let mut chosen = None;
+        let mut new_directory = None;
+        let mut contains_mouse = false;
+        let mut window_open = true;
+        let response = egui::Window::new(title)
+            .open(&mut window_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.button("Up").clicked() {
+                        new_directory = self.directory.parent().map(Path::to_path_buf);
+                    }
+                    ui.label(self.directory.display().to_string());
+                });
+
+                ui.separator();
+
+                egui::ScrollArea::from_max_height(LIST_HEIGHT).show(ui, |ui| {
+                    for (name, is_directory) in &self.entries {
+                        if *is_directory {
+                            if ui.selectable_label(false, format!("{}/", name)).clicked() {
+                                new_directory = Some(self.directory.join(name));
+                            }
+                        } else if ui.selectable_label(*name == self.file_name, name).clicked() {
+                            self.file_name = name.clone();
+                        }
+                    }
+                });
+
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    ui.label("File name:");
+                    ui.text_edit_singleline(&mut self.file_name);
+                });
+
+                if let Some(error) = &self.error {
+                    ui.add(egui::widgets::Label::new(error).text_color(REJECT_COLOR));
+                }
+
+                ui.horizontal(|ui| {
+                    let confirm_button = egui::widgets::Button::new(confirm_label)
+                        .enabled(!self.file_name.trim().is_empty());
+                    if ui.add(confirm_button).clicked() {
+                        let mut path = self.directory.join(self.file_name.trim());
+                        // Saved files are given the JFF extension if they have none
+                        if purpose == FileBrowserPurpose::SaveAs && path.extension().is_none() {
+                            path.set_extension(JFF_EXTENSION);
+                        }
+                        chosen = Some(path);
+                    }
+
+                    if ui.button("Cancel").clicked() {
+                        self.purpose = None;
+                    }
+                });
+            });
+        if !window_open {
+            self.purpose = None;
+        }
+
+        if let Some(inner_response) = response {
+            contains_mouse |= inner_response.response.hovered();
+        }
+
+        if let Some(directory) = new_directory {
+            self.directory = directory;
+            self.error = None;
+            self.read_directory();
+        }
+
+        (chosen, contains_mouse)
+    }
+}
+
+impl TopPanel {
+    pub(super) fn show_file_browser(&mut self, egui_ctx: &egui::CtxRef) {
+        let (chosen, contains_mouse) = self.file_browser.show(egui_ctx);
+        self.contains_mouse |= contains_mouse;
+        let (purpose, path) = match (self.file_browser.purpose, chosen) {
+            (Some(purpose), Some(path)) => (purpose, path),
+            _ => return,
+        };
+
+        // Files which cannot be read are reported in the browser, which stays open
+        let command = match purpose {
+            FileBrowserPurpose::SaveAs => DocumentCommand::SaveJFFAs(path),
+            FileBrowserPurpose::Open | FileBrowserPurpose::Compare => {
+                match fs::read_to_string(&path) {
+                    Ok(content) if purpose == FileBrowserPurpose::Open => {
+                        DocumentCommand::OpenJFF(content, Some(path))
+                    }
+                    Ok(content) => DocumentCommand::CompareJFF(content),
+                    Err(error) => {
+                        self.file_browser.error = Some(error.to_string());
+                        return;
+                    }
+                }
+            }
+        };
+        self.file_browser.purpose = None;
+
+        DOCUMENT_COMMAND_BUFFER.with(|buff| {
+            if let Ok(mut buff) = buff.try_borrow_mut() {
+                buff.push(command);
+            }
+        });
+    }
+
+    pub(super) fn show_file_error_window(&mut self, egui_ctx: &egui::CtxRef, file_error: String) {
+        let mut error_open = true;
+        egui::Window::new("Could Not Save File")
+            .open(&mut error_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                ui.add(egui::widgets::Label::new(file_error).text_color(REJECT_COLOR));
+            });
+        if !error_open {
+            self.file_error = None;
+        }
+    }
+}
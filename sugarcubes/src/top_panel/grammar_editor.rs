@@ -0,0 +1,157 @@
+use super::{PanelDocument, TopPanel, REJECT_COLOR};
+use crate::{DocumentCommand, DOCUMENT_COMMAND_BUFFER};
+use sugarcubes_core::grammar::{
+    Production, PushdownConstruction, EMPTY_STRING_SYMBOL, PRODUCTION_ARROW,
+};
+
+const SIDE_WIDTH: f32 = 200.;
+
+impl TopPanel {
+    // Show the grammar document as a table of productions, in place of the automaton canvas
+    pub(super) fn grammar_editor(&mut self, egui_ctx: &egui::CtxRef) {
+        let grammar = match &mut self.panel_document {
+            Some(PanelDocument::Grammar(grammar)) => grammar,
+            _ => return,
+        };
+        let cyk_input_window = &mut self.cyk_input_window;
+        let transformation_steps = &mut self.transformation_steps;
+        let transformation_index = &mut self.transformation_index;
+        let greibach_conversion = &mut self.greibach_conversion;
+        let brute_force_open = &mut self.brute_force_open;
+
+        egui::CentralPanel::default().show(egui_ctx, |ui| {
+            ui.heading("Grammar");
+            ui.label(format!(
+                "Uppercase letters are variables, and an empty right side is {}",
+                EMPTY_STRING_SYMBOL
+            ));
+            ui.separator();
+
+            let mut removed = None;
+            egui::ScrollArea::auto_sized().show(ui, |ui| {
+                egui::Grid::new("grammar_productions")
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (i, production) in grammar.productions.iter_mut().enumerate() {
+                            // Productions which replace no variable are marked as invalid
+                            let color = if production.is_valid() {
+                                None
+                            } else {
+                                Some(REJECT_COLOR)
+                            };
+                            ui.add(
+                                egui::TextEdit::singleline(&mut production.lhs)
+                                    .text_style(egui::TextStyle::Monospace)
+                                    .text_color_opt(color)
+                                    .desired_width(SIDE_WIDTH),
+                            );
+                            ui.label(PRODUCTION_ARROW.to_string());
+                            ui.add(
+                                egui::TextEdit::singleline(&mut production.rhs)
+                                    .text_style(egui::TextStyle::Monospace)
+                                    .hint_text(EMPTY_STRING_SYMBOL)
+                                    .desired_width(SIDE_WIDTH),
+                            );
+                            if ui.small_button("X").clicked() {
+                                removed = Some(i);
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                if ui.button("Add Production").clicked() {
+                    let lhs = grammar.start_symbol.to_string();
+                    grammar.productions.push(Production::new(&lhs, ""));
+                }
+            });
+
+            if let Some(removed) = removed {
+                grammar.productions.remove(removed);
+            }
+
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                let mut start_symbol = grammar.start_symbol;
+                egui::ComboBox::from_label("Start symbol")
+                    .selected_text(start_symbol)
+                    .show_ui(ui, |ui| {
+                        for variable in grammar.variables() {
+                            ui.selectable_value(&mut start_symbol, variable, variable.to_string());
+                        }
+                    });
+                grammar.start_symbol = start_symbol;
+            });
+
+            ui.label(format!("Type: {}", grammar.classify()));
+            ui.label(format!(
+                "Variables: {}",
+                grammar.variables().into_iter().collect::<String>()
+            ));
+            ui.label(format!(
+                "Terminals: {}",
+                grammar.terminals().into_iter().collect::<String>()
+            ));
+
+            ui.separator();
+
+            // CYK only applies to grammars in Chomsky normal form
+            let is_chomsky_normal_form = grammar.is_chomsky_normal_form();
+            ui.horizontal(|ui| {
+                let cyk_button =
+                    egui::widgets::Button::new("CYK Parse...").enabled(is_chomsky_normal_form);
+                if ui.add(cyk_button).clicked() {
+                    cyk_input_window.open = true;
+                }
+                if !is_chomsky_normal_form {
+                    ui.label("Requires Chomsky normal form");
+                }
+            });
+
+            // Brute force parsing works for any grammar, but may not finish within its limits
+            if ui.button("Brute Force Parse...").clicked() {
+                *brute_force_open = true;
+            }
+
+            ui.horizontal(|ui| {
+                if ui.button("Transform to CNF...").clicked() {
+                    *transformation_steps = Some(grammar.chomsky_normal_form_steps());
+                    *transformation_index = 0;
+                }
+                if ui.button("Transform to GNF...").clicked() {
+                    *greibach_conversion = Some(grammar.greibach_normal_form());
+                }
+            });
+
+            // Finite automata can only be built for right-linear grammars,
+            // and pushdown automata for context-free grammars
+            let is_right_linear = grammar.is_right_linear();
+            let is_context_free = grammar.is_context_free();
+            ui.horizontal(|ui| {
+                let convert_button =
+                    egui::widgets::Button::new("Convert to FA").enabled(is_right_linear);
+                if ui.add(convert_button).clicked() {
+                    DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                        if let Ok(mut buff) = buff.try_borrow_mut() {
+                            buff.push(DocumentCommand::ConvertGrammarToFA);
+                        }
+                    });
+                }
+                for &construction in &[PushdownConstruction::Ll, PushdownConstruction::Lr] {
+                    let convert_button =
+                        egui::widgets::Button::new(format!("Convert to PDA ({})", construction))
+                            .enabled(is_context_free);
+                    if ui.add(convert_button).clicked() {
+                        DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                            if let Ok(mut buff) = buff.try_borrow_mut() {
+                                buff.push(DocumentCommand::ConvertGrammarToPDA(construction));
+                            }
+                        });
+                    }
+                }
+            });
+        });
+
+        self.contains_mouse = true;
+    }
+}
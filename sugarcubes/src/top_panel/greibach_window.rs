@@ -0,0 +1,72 @@
+use super::{PanelDocument, TopPanel, REJECT_COLOR};
+
+const LOG_HEIGHT: f32 = 200.;
+const GRAMMAR_HEIGHT: f32 = 300.;
+
+impl TopPanel {
+    // Show the rewrites converting the grammar to Greibach normal form, and the result
+    pub(super) fn show_greibach_window(&mut self, egui_ctx: &egui::CtxRef) {
+        let conversion = match &self.greibach_conversion {
+            Some(conversion) => conversion,
+            None => return,
+        };
+
+        let mut window_open = true;
+        let mut chosen_grammar = None;
+        let response = egui::Window::new("Greibach Normal Form")
+            .open(&mut window_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                let conversion = match conversion {
+                    Ok(conversion) => conversion,
+                    Err(error) => {
+                        ui.add(egui::Label::new(error.to_string()).text_color(REJECT_COLOR));
+                        return;
+                    }
+                };
+
+                ui.heading("Rewrites");
+                egui::ScrollArea::from_max_height(LOG_HEIGHT)
+                    .id_source("greibach_log")
+                    .show(ui, |ui| {
+                        for (i, rewrite) in conversion.log.iter().enumerate() {
+                            ui.label(format!("{}. {}", i + 1, rewrite));
+                        }
+                    });
+
+                ui.separator();
+
+                ui.heading("Result");
+                egui::ScrollArea::from_max_height(GRAMMAR_HEIGHT)
+                    .id_source("greibach_grammar")
+                    .show(ui, |ui| {
+                        if conversion.grammar.productions.is_empty() {
+                            ui.label("The grammar generates no strings");
+                        }
+                        for production in &conversion.grammar.productions {
+                            ui.add(egui::Label::new(production.to_string()).monospace());
+                        }
+                    });
+
+                ui.separator();
+
+                if ui.button("Use This Grammar").clicked() {
+                    chosen_grammar = Some(conversion.grammar.clone());
+                }
+            });
+
+        if let Some(inner_response) = response {
+            self.contains_mouse |= inner_response.response.hovered();
+        }
+
+        if let Some(grammar) = chosen_grammar {
+            self.panel_document = Some(PanelDocument::Grammar(grammar));
+            window_open = false;
+        }
+
+        if !window_open {
+            self.greibach_conversion = None;
+        }
+    }
+}
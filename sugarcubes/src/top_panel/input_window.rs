@@ -59,7 +59,7 @@ impl InputWindow {
                         .fonts()
                         .layout_single_line(text_style, self.input.clone());
                     if let Some(cursorp) = &mut text_edit_state.cursorp {
-                        (*cursorp).primary = galley.cursor_end_of_row(&cursorp.primary);
+                        cursorp.primary = galley.cursor_end_of_row(&cursorp.primary);
                     }
                 }
 
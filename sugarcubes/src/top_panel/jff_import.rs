@@ -0,0 +1,44 @@
+use super::{TopPanel, REJECT_COLOR};
+use sugarcubes_core::jff::JffError;
+
+const WARNINGS_HEIGHT: f32 = 200.;
+
+impl TopPanel {
+    pub(super) fn show_jff_error_window(&mut self, egui_ctx: &egui::CtxRef, jff_error: JffError) {
+        let mut error_open = true;
+        egui::Window::new("Could Not Open File")
+            .open(&mut error_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                ui.add(egui::widgets::Label::new(jff_error.to_string()).text_color(REJECT_COLOR));
+            });
+        if !error_open {
+            self.jff_error = None;
+        }
+    }
+
+    // List the parts of the file which were skipped, since the rest could still be opened
+    pub(super) fn show_jff_warnings_window(&mut self, egui_ctx: &egui::CtxRef) {
+        let mut warnings_open = true;
+        let warnings = &self.jff_warnings;
+        egui::Window::new("Opened With Warnings")
+            .open(&mut warnings_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                ui.label(format!(
+                    "{} part(s) of the file could not be read, and were skipped:",
+                    warnings.len()
+                ));
+                egui::ScrollArea::from_max_height(WARNINGS_HEIGHT).show(ui, |ui| {
+                    for warning in warnings {
+                        ui.label(warning.to_string());
+                    }
+                });
+            });
+        if !warnings_open {
+            self.jff_warnings.clear();
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use super::{Mode, TopPanel, TOP_PANEL};
-use crate::{FiniteAutomaton, SimulateAutomaton};
+use crate::model::{Model, RunResult};
 
 use sapp_jsutils::JsObject;
 
@@ -23,7 +23,7 @@ extern "C" fn read_multiple_run_inputs(content: JsObject) {
 }
 
 impl TopPanel {
-    pub(super) fn left_panel(&mut self, egui_ctx: &egui::CtxRef, fa: &FiniteAutomaton) {
+    pub(super) fn left_panel(&mut self, egui_ctx: &egui::CtxRef, model: &Model) {
         egui::SidePanel::left("multiple_run")
             .resizable(false)
             .show(egui_ctx, |ui| {
@@ -54,12 +54,12 @@ impl TopPanel {
                     ui.horizontal(|ui| {
                         if ui.button("Run").clicked() {
                             for (text, status) in self.multiple_run_strings.iter_mut() {
-                                *status = Some(fa.check_input(text));
+                                *status = Some(model.run(text));
                             }
 
                             // If the last string is empty, discard the result,
                             // as it is most likely just an extra blank line, not a user's query
-                            if let Some(mut last_string) = self.multiple_run_strings.last_mut() {
+                            if let Some(last_string) = self.multiple_run_strings.last_mut() {
                                 if last_string.0.is_empty() {
                                     last_string.1 = None;
                                 }
@@ -114,9 +114,13 @@ impl TopPanel {
                 }
 
                 let label = match status {
-                    None => "⛶",
-                    Some(false) => "🗙",
-                    Some(true) => "✔",
+                    None => "⛶".to_string(),
+                    Some(RunResult::Rejected) => "🗙".to_string(),
+                    Some(RunResult::Accepted) => "✔".to_string(),
+                    // The search was cut short before accepting
+                    Some(RunResult::Truncated) => "?".to_string(),
+                    // The machine was still running when the step limit was reached
+                    Some(RunResult::DidNotHalt(steps)) => format!("> {} steps", steps),
                 };
                 ui.add(egui::Label::new(label));
             });
@@ -0,0 +1,189 @@
+use super::{Mode, PanelDocument, TopPanel, ACCEPT_COLOR, REJECT_COLOR};
+use sugarcubes_core::grammar::{LlAction, LlParseTable, EMPTY_STRING_SYMBOL};
+
+use std::collections::BTreeSet;
+
+impl TopPanel {
+    // Show the FIRST and FOLLOW sets and LL(1) parse table of the grammar,
+    // and trace parsing a string with it
+    pub(super) fn ll_parse_panel(&mut self, egui_ctx: &egui::CtxRef) {
+        let grammar = match &self.panel_document {
+            Some(PanelDocument::Grammar(grammar)) => grammar,
+            _ => return,
+        };
+
+        // Rebuild the table whenever the grammar is edited, discarding the old trace
+        let table_outdated = self
+            .ll_parse_table
+            .as_ref()
+            .is_none_or(|table| table.grammar() != grammar);
+        if table_outdated {
+            self.ll_parse_table = LlParseTable::new(grammar);
+            self.ll_parse_trace.clear();
+            self.ll_parse_shown_steps = 0;
+        }
+
+        let table = &self.ll_parse_table;
+        let mode = &mut self.mode;
+        let input = &mut self.ll_parse_input;
+        let trace = &mut self.ll_parse_trace;
+        let shown_steps = &mut self.ll_parse_shown_steps;
+
+        egui::SidePanel::right("ll_parse")
+            .resizable(false)
+            .show(egui_ctx, |ui| {
+                // Title bar and close button
+                ui.horizontal(|ui| {
+                    ui.with_layout(egui::Layout::left_to_right(), |ui| {
+                        ui.heading("LL(1) Parse");
+                    });
+                    ui.with_layout(egui::Layout::right_to_left(), |ui| {
+                        if ui.button("X").clicked() {
+                            *mode = Mode::Edit;
+                        }
+                    });
+                });
+
+                ui.separator();
+
+                let table = match table {
+                    Some(table) => table,
+                    None => {
+                        ui.label("Requires a context-free grammar");
+                        return;
+                    }
+                };
+
+                egui::ScrollArea::auto_sized().show(ui, |ui| {
+                    first_follow_grid(ui, table);
+                    ui.separator();
+
+                    let conflicts = table.conflicts();
+                    parse_table_grid(ui, table, &conflicts);
+                    if !conflicts.is_empty() {
+                        ui.add(
+                            egui::Label::new(format!(
+                                "Not LL(1): {} conflicting cell(s)",
+                                conflicts.len()
+                            ))
+                            .text_color(REJECT_COLOR),
+                        );
+                        return;
+                    }
+                    ui.separator();
+
+                    ui.horizontal(|ui| {
+                        ui.label("Input:");
+                        ui.text_edit_singleline(input);
+                    });
+                    ui.horizontal(|ui| {
+                        if ui.button("Start").clicked() {
+                            *trace = table.parse(input).unwrap_or_default();
+                            *shown_steps = 1;
+                        }
+
+                        let can_step = *shown_steps < trace.len();
+                        let step_button = egui::widgets::Button::new("Step").enabled(can_step);
+                        if ui.add(step_button).clicked() {
+                            *shown_steps += 1;
+                        }
+                        let complete_button =
+                            egui::widgets::Button::new("Complete").enabled(can_step);
+                        if ui.add(complete_button).clicked() {
+                            *shown_steps = trace.len();
+                        }
+                    });
+
+                    let shown_trace = &trace[..*shown_steps];
+                    if !shown_trace.is_empty() {
+                        egui::Grid::new("ll_parse_trace")
+                            .striped(true)
+                            .show(ui, |ui| {
+                                ui.add(egui::Label::new("Stack").strong());
+                                ui.add(egui::Label::new("Input").strong());
+                                ui.add(egui::Label::new("Action").strong());
+                                ui.end_row();
+
+                                for step in shown_trace {
+                                    ui.add(egui::Label::new(&step.stack).monospace());
+                                    ui.add(egui::Label::new(&step.remaining).monospace());
+                                    let action = egui::Label::new(step.action.to_string());
+                                    ui.add(match step.action {
+                                        LlAction::Accept => action.text_color(ACCEPT_COLOR),
+                                        LlAction::Reject => action.text_color(REJECT_COLOR),
+                                        _ => action,
+                                    });
+                                    ui.end_row();
+                                }
+                            });
+                    }
+                });
+            });
+
+        self.contains_mouse = true;
+    }
+}
+
+fn first_follow_grid(ui: &mut egui::Ui, table: &LlParseTable) {
+    let set_text = |set: &BTreeSet<char>| {
+        set.iter()
+            .map(char::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    egui::Grid::new("ll_first_follow")
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("");
+            ui.add(egui::Label::new("FIRST").strong());
+            ui.add(egui::Label::new("FOLLOW").strong());
+            ui.end_row();
+
+            for variable in table.variables() {
+                ui.add(egui::Label::new(variable.to_string()).monospace().strong());
+                ui.add(egui::Label::new(set_text(&table.first_sets()[&variable])).monospace());
+                ui.add(egui::Label::new(set_text(&table.follow_sets()[&variable])).monospace());
+                ui.end_row();
+            }
+        });
+}
+
+// Each cell lists the right sides of its productions, with conflicting cells highlighted
+fn parse_table_grid(ui: &mut egui::Ui, table: &LlParseTable, conflicts: &[(char, char)]) {
+    egui::Grid::new("ll_parse_table")
+        .striped(true)
+        .show(ui, |ui| {
+            let lookaheads = table.lookaheads();
+            ui.label("");
+            for lookahead in &lookaheads {
+                ui.add(egui::Label::new(lookahead.to_string()).monospace().strong());
+            }
+            ui.end_row();
+
+            for variable in table.variables() {
+                ui.add(egui::Label::new(variable.to_string()).monospace().strong());
+                for &lookahead in &lookaheads {
+                    let text = table
+                        .entry(variable, lookahead)
+                        .iter()
+                        .map(|production| {
+                            if production.is_empty() {
+                                EMPTY_STRING_SYMBOL.to_string()
+                            } else {
+                                production.rhs.clone()
+                            }
+                        })
+                        .collect::<Vec<String>>()
+                        .join(", ");
+                    let label = egui::Label::new(text).monospace();
+                    if conflicts.contains(&(variable, lookahead)) {
+                        ui.add(label.text_color(REJECT_COLOR));
+                    } else {
+                        ui.add(label);
+                    }
+                }
+                ui.end_row();
+            }
+        });
+}
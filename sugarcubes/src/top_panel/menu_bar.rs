@@ -1,63 +1,186 @@
-use super::{Mode, TopPanel, TopPanelCommand};
-use crate::{DocumentCommand, DOCUMENT_COMMAND_BUFFER};
+use super::{
+    file_browser::FileBrowserPurpose, unsaved_changes::DiscardingAction, Mode, PanelDocument,
+    TopPanel, TopPanelCommand,
+};
+use crate::{model::Model, DocumentCommand, DOCUMENT_COMMAND_BUFFER};
+use sugarcubes_core::{
+    automata::finite_automaton::{FiniteAutomaton, StatePartition},
+    regex::GeneralizedAutomaton,
+};
 
 #[cfg(target_arch = "wasm32")]
 extern "C" {
     fn choose_jff_file();
+    fn choose_compare_jff_file();
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-unsafe fn choose_jff_file() {}
-
 impl TopPanel {
     pub(super) fn menu_bar(
         &mut self,
         ui: &mut egui::Ui,
+        model: &Model,
         can_undo: bool,
         can_redo: bool,
+        unsaved_changes: bool,
     ) -> Option<TopPanelCommand> {
         let mut command = None;
 
         egui::menu::bar(ui, |ui| {
-            self.file_menu(ui);
+            let file_menu_command = self.file_menu(ui, unsaved_changes);
+            if let Some(file_menu_command) = file_menu_command {
+                command = Some(file_menu_command);
+            }
 
-            let edit_menu_command = self.edit_menu(ui, can_undo, can_redo);
-            if let Some(edit_menu_command) = edit_menu_command {
-                command = Some(edit_menu_command);
+            if let Some(PanelDocument::Grammar(_)) = self.panel_document {
+                self.parse_menu(ui);
             }
 
-            self.simulate_menu(ui);
+            // The remaining menus only apply to automata
+            if self.panel_document.is_none() {
+                let edit_menu_command = self.edit_menu(ui, can_undo, can_redo);
+                if let Some(edit_menu_command) = edit_menu_command {
+                    command = Some(edit_menu_command);
+                }
+
+                self.simulate_menu(ui);
+
+                match model {
+                    Model::FiniteAutomaton(fa) => self.operations_menu(ui, fa),
+                    Model::PushdownAutomaton(_) => self.pushdown_operations_menu(ui),
+                    _ => {}
+                }
+            }
         });
 
         command
     }
 
-    fn file_menu(&mut self, ui: &mut egui::Ui) {
+    fn file_menu(&mut self, ui: &mut egui::Ui, unsaved_changes: bool) -> Option<TopPanelCommand> {
+        let mut command = None;
         egui::menu::menu(ui, "File", |ui| {
-            if ui.button("New").clicked() {
-                DOCUMENT_COMMAND_BUFFER.with(|buff| {
-                    if let Ok(mut buff) = buff.try_borrow_mut() {
-                        buff.push(DocumentCommand::NewFile);
-                    }
-                });
+            if ui.button("New Tab").clicked() {
+                command = Some(TopPanelCommand::NewTab);
             }
 
-            if ui.button("Open...").clicked() {
-                unsafe {
-                    choose_jff_file();
+            ui.separator();
+
+            if ui.button("New Automaton").clicked() {
+                command = self.discard_document(
+                    DiscardingAction::Document(DocumentCommand::NewFile),
+                    unsaved_changes,
+                );
+            }
+
+            if ui.button("New Regular Expression").clicked() {
+                command = self.discard_document(
+                    DiscardingAction::Document(DocumentCommand::NewRegex(String::new())),
+                    unsaved_changes,
+                );
+            }
+
+            if ui.button("New Grammar").clicked() {
+                command = self.discard_document(
+                    DiscardingAction::Document(DocumentCommand::NewGrammar),
+                    unsaved_changes,
+                );
+            }
+
+            ui.separator();
+
+            for &(label, tape_count) in &[
+                ("New Turing Machine", 1),
+                ("New 2-Tape Turing Machine", 2),
+                ("New 3-Tape Turing Machine", 3),
+            ] {
+                if ui.button(label).clicked() {
+                    command = self.discard_document(
+                        DiscardingAction::Document(DocumentCommand::NewTuringMachine(tape_count)),
+                        unsaved_changes,
+                    );
                 }
             }
 
-            // TODO: Split into "Save" and "Save as..."
-            // where the former is aware of the current file's name, if any
+            ui.separator();
+
+            if ui.button("New Mealy Machine").clicked() {
+                command = self.discard_document(
+                    DiscardingAction::Document(DocumentCommand::NewMealyMachine),
+                    unsaved_changes,
+                );
+            }
+
+            if ui.button("New Moore Machine").clicked() {
+                command = self.discard_document(
+                    DiscardingAction::Document(DocumentCommand::NewMooreMachine),
+                    unsaved_changes,
+                );
+            }
+
+            ui.separator();
+
+            if ui.button("New Pushdown Automaton").clicked() {
+                command = self.discard_document(
+                    DiscardingAction::Document(DocumentCommand::NewPushdownAutomaton),
+                    unsaved_changes,
+                );
+            }
+
+            ui.separator();
+
+            if ui.button("Open...").clicked() {
+                command = self.discard_document(DiscardingAction::Open, unsaved_changes);
+            }
+
+            // Without a file to overwrite, saving asks for one like "Save As..."
             if ui.button("Save").clicked() {
-                DOCUMENT_COMMAND_BUFFER.with(|buff| {
-                    if let Ok(mut buff) = buff.try_borrow_mut() {
-                        buff.push(DocumentCommand::SaveJFF);
-                    }
-                });
+                if self.file_path.is_some() {
+                    DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                        if let Ok(mut buff) = buff.try_borrow_mut() {
+                            buff.push(DocumentCommand::SaveJFF);
+                        }
+                    });
+                } else {
+                    self.choose_file(FileBrowserPurpose::SaveAs);
+                }
+            }
+
+            if ui.button("Save As...").clicked() {
+                self.choose_file(FileBrowserPurpose::SaveAs);
+            }
+
+            ui.separator();
+
+            if ui.button("Close Tab").clicked() {
+                command = self.discard_document(DiscardingAction::CloseTab, unsaved_changes);
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if ui.button("Quit").clicked() {
+                command = self.discard_document(DiscardingAction::Quit, unsaved_changes);
             }
         });
+        command
+    }
+
+    // Ask the user for a file with the browser's file picker on the web,
+    // where saving always lets the user choose where to save
+    #[cfg(target_arch = "wasm32")]
+    pub(super) fn choose_file(&mut self, purpose: FileBrowserPurpose) {
+        match purpose {
+            FileBrowserPurpose::Open => unsafe { choose_jff_file() },
+            FileBrowserPurpose::Compare => unsafe { choose_compare_jff_file() },
+            FileBrowserPurpose::SaveAs => DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                if let Ok(mut buff) = buff.try_borrow_mut() {
+                    buff.push(DocumentCommand::SaveJFF);
+                }
+            }),
+        }
+    }
+
+    // Ask the user for a file with the built-in file browser on the desktop
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(super) fn choose_file(&mut self, purpose: FileBrowserPurpose) {
+        self.file_browser.open(purpose, self.file_path.as_deref());
     }
 
     fn edit_menu(
@@ -100,4 +223,82 @@ impl TopPanel {
             }
         });
     }
+
+    fn parse_menu(&mut self, ui: &mut egui::Ui) {
+        egui::menu::menu(ui, "Parse", |ui| {
+            if ui.button("LL(1) Parse").clicked() {
+                self.mode = Mode::LlParse;
+                self.ll_parse_trace.clear();
+                self.ll_parse_shown_steps = 0;
+            }
+
+            if ui.button("SLR(1) Parse").clicked() {
+                self.mode = Mode::SlrParse;
+                self.slr_parse_trace.clear();
+                self.slr_parse_shown_steps = 0;
+            }
+        });
+    }
+
+    fn operations_menu(&mut self, ui: &mut egui::Ui, fa: &FiniteAutomaton) {
+        egui::menu::menu(ui, "Operations", |ui| {
+            if ui.button("Convert to DFA").clicked() {
+                DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                    if let Ok(mut buff) = buff.try_borrow_mut() {
+                        buff.push(DocumentCommand::ConvertToDFA);
+                    }
+                });
+            }
+
+            let is_deterministic = fa.is_deterministic();
+            let minimize_button =
+                egui::widgets::Button::new("Minimize DFA").enabled(is_deterministic);
+            if ui.add(minimize_button).clicked() {
+                DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                    if let Ok(mut buff) = buff.try_borrow_mut() {
+                        buff.push(DocumentCommand::MinimizeDFA);
+                    }
+                });
+            }
+
+            let minimize_step_button = egui::widgets::Button::new("Minimize DFA Step by Step...")
+                .enabled(is_deterministic);
+            if ui.add(minimize_step_button).clicked() {
+                self.minimize_partition = StatePartition::new(fa);
+                self.minimize_message.clear();
+            }
+
+            ui.separator();
+
+            if ui.button("Convert to Regular Expression...").clicked() {
+                self.state_elimination = Some(GeneralizedAutomaton::new(fa));
+            }
+
+            if ui.button("Convert to Right-Linear Grammar").clicked() {
+                DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                    if let Ok(mut buff) = buff.try_borrow_mut() {
+                        buff.push(DocumentCommand::ConvertFAToGrammar);
+                    }
+                });
+            }
+
+            ui.separator();
+
+            if ui.button("Compare Equivalence...").clicked() {
+                self.choose_file(FileBrowserPurpose::Compare);
+            }
+        });
+    }
+
+    fn pushdown_operations_menu(&mut self, ui: &mut egui::Ui) {
+        egui::menu::menu(ui, "Operations", |ui| {
+            if ui.button("Convert to Grammar").clicked() {
+                DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                    if let Ok(mut buff) = buff.try_borrow_mut() {
+                        buff.push(DocumentCommand::ConvertPDAToGrammar);
+                    }
+                });
+            }
+        });
+    }
 }
@@ -0,0 +1,107 @@
+use super::TopPanel;
+use crate::{DocumentCommand, States, DOCUMENT_COMMAND_BUFFER};
+
+impl TopPanel {
+    pub(super) fn show_minimize_window(&mut self, egui_ctx: &egui::CtxRef, states: &mut States) {
+        let partition = match &mut self.minimize_partition {
+            Some(partition) => partition,
+            None => return,
+        };
+        let message = &mut self.minimize_message;
+
+        let mut window_open = true;
+        let mut finished = false;
+        let response = egui::Window::new("Minimize DFA")
+            .open(&mut window_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                ui.label("Groups of states not yet distinguished:");
+                ui.separator();
+
+                // Each group can be split on a chosen symbol, or expanded automatically
+                let mut split = None;
+                for (index, group) in partition.groups().iter().enumerate() {
+                    let names: Vec<String> = group
+                        .iter()
+                        .map(|&state| {
+                            if partition.trap_state() == Some(state) {
+                                "trap".to_string()
+                            } else {
+                                states.get_name(state)
+                            }
+                        })
+                        .collect();
+
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{}: {{{}}}", index + 1, names.join(", ")));
+                        for &symbol in partition.alphabet() {
+                            if ui.small_button(symbol.to_string()).clicked() {
+                                split = Some((index, Some(symbol)));
+                            }
+                        }
+
+                        let expand_button = egui::widgets::Button::new("Expand")
+                            .enabled(partition.distinguishing_symbol(index).is_some());
+                        if ui.add(expand_button).clicked() {
+                            split = Some((index, None));
+                        }
+                    });
+                }
+
+                if let Some((index, symbol)) = split {
+                    let group_name = format!("group {}", index + 1);
+                    *message = match symbol {
+                        Some(symbol) if partition.split_group_on(index, symbol) => {
+                            format!("Split {} on \"{}\"", group_name, symbol)
+                        }
+                        Some(symbol) => {
+                            format!("\"{}\" does not distinguish {}", symbol, group_name)
+                        }
+                        None => match partition.split_group(index) {
+                            Some(symbol) => format!("Split {} on \"{}\"", group_name, symbol),
+                            None => format!("{} cannot be split", group_name),
+                        },
+                    };
+                }
+
+                ui.separator();
+                ui.label(message.as_str());
+
+                let is_complete = partition.is_complete();
+                ui.horizontal(|ui| {
+                    let complete_button =
+                        egui::widgets::Button::new("Complete").enabled(!is_complete);
+                    if ui.add(complete_button).clicked() {
+                        partition.complete();
+                        *message = "Split all distinguishable groups".to_string();
+                    }
+
+                    let finish_button = egui::widgets::Button::new("Finish").enabled(is_complete);
+                    if ui.add(finish_button).clicked() {
+                        finished = true;
+                    }
+                });
+            });
+
+        if let Some(inner_response) = response {
+            self.contains_mouse |= inner_response.response.hovered();
+        }
+
+        // The result is built from the partition shown, rather than minimizing again
+        if finished {
+            if let Some(partition) = self.minimize_partition.take() {
+                DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                    if let Ok(mut buff) = buff.try_borrow_mut() {
+                        buff.push(DocumentCommand::MinimizeDFAWithPartition(partition));
+                    }
+                });
+            }
+        }
+
+        if !window_open || finished {
+            self.minimize_partition = None;
+            self.minimize_message.clear();
+        }
+    }
+}
@@ -1,23 +1,52 @@
+mod brute_force_window;
 mod context_menu;
+mod conversion_error;
+mod cyk_window;
+mod equivalence;
 mod fast_run;
+mod file_browser;
+mod grammar_editor;
+mod greibach_window;
 mod input_window;
+mod jff_import;
 mod left_panel;
+mod ll_parse_panel;
 mod menu_bar;
+mod minimize_window;
+mod parse_tree_window;
+mod regex_editor;
 mod set_name;
+mod set_output;
 mod simulate_input_window;
 mod simulation_toolbar;
-
+mod slr_parse_panel;
+mod state_elimination;
+mod tab_bar;
+mod transform_window;
+mod unsaved_changes;
+
+pub use equivalence::ComparisonError;
+use file_browser::FileBrowser;
 use input_window::InputWindow;
+pub use unsaved_changes::DiscardingAction;
+
+use crate::{command::*, document::Document, model::*};
 
-use crate::{command::*, states::*};
+use sugarcubes_core::automata::finite_automaton::{Equivalence, StatePartition};
 
-use sugarcubes_core::automata::finite_automaton::{
-    FiniteAutomaton, FiniteAutomatonConfiguration, FiniteAutomatonTransition,
+use sugarcubes_core::{
+    grammar::{
+        CykTable, DerivationLimits, DerivationResult, Grammar, GreibachConversion, LlParseStep,
+        LlParseTable, ParseTree, SlrParseStep, SlrParseTable, TransformError, TransformationStep,
+    },
+    jff::JffError,
+    regex::GeneralizedAutomaton,
 };
 
 use macroquad::prelude::*;
 
 use std::cell::RefCell;
+use std::path::PathBuf;
 
 pub const ACCEPT_COLOR: egui::Color32 = egui::Color32::from_rgb(122, 240, 98);
 pub const REJECT_COLOR: egui::Color32 = egui::Color32::RED;
@@ -29,6 +58,15 @@ pub enum Mode {
     Edit,
     Simulate,
     MultipleRun,
+    LlParse,
+    SlrParse,
+}
+
+// A document edited entirely within the top panel, in place of an automaton
+#[derive(Clone, PartialEq)]
+pub enum PanelDocument {
+    Regex(String),
+    Grammar(Grammar),
 }
 
 pub enum TopPanelCommand {
@@ -36,7 +74,13 @@ pub enum TopPanelCommand {
     Undo,
     Redo,
     Step,
-    StartSimulation(Vec<FiniteAutomatonConfiguration>),
+    StartSimulation(Configurations),
+    NewTab,
+    SwitchTab(usize),
+    // Close the current tab, whose unsaved changes the user has already been asked about
+    CloseTab,
+    // Close the current tab as with CloseTab, then each of the others in turn, and then quit
+    Quit,
 }
 
 pub struct TopPanel {
@@ -47,18 +91,77 @@ pub struct TopPanel {
     pub open_context_menu: bool,
     pub context_menu_pos: Vec2,
 
+    // The current document, if it is edited in the top panel rather than being an automaton
+    pub panel_document: Option<PanelDocument>,
+
     simulate_input_window: InputWindow,
     string_simulating: String,
 
     fast_run_input_window: InputWindow,
     fast_run_string: String,
-    fast_run_result: Option<bool>,
+    fast_run_result: Option<RunResult>,
 
-    multiple_run_strings: Vec<(String, Option<bool>)>,
+    multiple_run_strings: Vec<(String, Option<RunResult>)>,
     multiple_run_selected_index: Option<usize>,
 
     set_name_input_window: InputWindow,
     set_name_state_id: Option<u32>,
+
+    set_output_input_window: InputWindow,
+    set_output_state_id: Option<u32>,
+
+    minimize_partition: Option<StatePartition>,
+    minimize_message: String,
+    // The revision of the model when the panel was last shown
+    model_revision: u64,
+
+    pub equivalence_result: Option<Equivalence>,
+    pub comparison_error: Option<ComparisonError>,
+
+    // Why converting the automaton into a grammar failed
+    pub conversion_error: Option<TransformError>,
+
+    // Why a JFF file could not be opened, or what was skipped while opening it
+    pub jff_error: Option<JffError>,
+    pub jff_warnings: Vec<JffError>,
+
+    // The file the document was opened from or last saved to, if any. Like the panel document
+    // and mode, it is handed back to the document when another tab is opened.
+    pub file_path: Option<PathBuf>,
+    // Why the document could not be saved
+    pub file_error: Option<String>,
+    file_browser: FileBrowser,
+
+    // What the user asked for while the document had unsaved changes, waiting on whether
+    // to save them first, and whether they are being saved
+    discarding_action: Option<DiscardingAction>,
+    saving_before_discard: bool,
+
+    state_elimination: Option<GeneralizedAutomaton>,
+
+    cyk_input_window: InputWindow,
+    cyk_table: Option<CykTable>,
+    parse_tree: Option<ParseTree>,
+
+    transformation_steps: Option<Result<Vec<TransformationStep>, TransformError>>,
+    transformation_index: usize,
+
+    greibach_conversion: Option<Result<GreibachConversion, TransformError>>,
+
+    ll_parse_table: Option<LlParseTable>,
+    ll_parse_input: String,
+    ll_parse_trace: Vec<LlParseStep>,
+    ll_parse_shown_steps: usize,
+
+    slr_parse_table: Option<SlrParseTable>,
+    slr_parse_input: String,
+    slr_parse_trace: Vec<SlrParseStep>,
+    slr_parse_shown_steps: usize,
+
+    brute_force_open: bool,
+    brute_force_input: String,
+    brute_force_limits: DerivationLimits,
+    brute_force_result: Option<DerivationResult>,
 }
 
 impl TopPanel {
@@ -71,6 +174,8 @@ impl TopPanel {
             open_context_menu: false,
             context_menu_pos: Vec2::ZERO,
 
+            panel_document: None,
+
             simulate_input_window: InputWindow::new("simulate"),
             string_simulating: String::new(),
 
@@ -83,22 +188,88 @@ impl TopPanel {
 
             set_name_input_window: InputWindow::new("set_name"),
             set_name_state_id: None,
+
+            set_output_input_window: InputWindow::new("set_output"),
+            set_output_state_id: None,
+
+            minimize_partition: None,
+            minimize_message: String::new(),
+            model_revision: 0,
+
+            equivalence_result: None,
+            comparison_error: None,
+
+            conversion_error: None,
+
+            jff_error: None,
+            jff_warnings: Vec::new(),
+
+            file_path: None,
+            file_error: None,
+            file_browser: FileBrowser::new(),
+
+            discarding_action: None,
+            saving_before_discard: false,
+
+            state_elimination: None,
+
+            cyk_input_window: InputWindow::new("cyk"),
+            cyk_table: None,
+            parse_tree: None,
+
+            transformation_steps: None,
+            transformation_index: 0,
+
+            greibach_conversion: None,
+
+            ll_parse_table: None,
+            ll_parse_input: String::new(),
+            ll_parse_trace: Vec::new(),
+            ll_parse_shown_steps: 0,
+
+            slr_parse_table: None,
+            slr_parse_input: String::new(),
+            slr_parse_trace: Vec::new(),
+            slr_parse_shown_steps: 0,
+
+            brute_force_open: false,
+            brute_force_input: String::new(),
+            brute_force_limits: DerivationLimits::default(),
+            brute_force_result: None,
         }
     }
 
     pub fn ui(
         &mut self,
-        fa: &FiniteAutomaton,
-        states: &mut States,
-        configurations: &mut Vec<FiniteAutomatonConfiguration>,
+        documents: &mut [Document],
+        current_document: usize,
         mouse_position: &Vec2,
         selected_state: &mut Option<u32>,
-        selected_transition: &mut Option<FiniteAutomatonTransition>,
-        can_undo: bool,
-        can_redo: bool,
+        selected_transition: &mut Option<ModelTransition>,
     ) -> Option<TopPanelCommand> {
         self.contains_mouse = false;
 
+        let tab_names = self.tab_names(documents, current_document);
+        let document = &mut documents[current_document];
+        let model = &document.model;
+        let states = &mut document.states;
+        let configurations = &document.configurations;
+        let can_undo = document.command_handler.can_undo();
+        let can_redo = document.command_handler.can_redo();
+        let unsaved_changes = document.command_handler.is_dirty();
+
+        // A partition being refined step by step no longer matches an automaton which changed
+        let model_revision = document.command_handler.revision();
+        if model_revision != self.model_revision {
+            self.model_revision = model_revision;
+            self.minimize_partition = None;
+            self.minimize_message.clear();
+        }
+
+        // Panel documents are edited in place rather than through commands,
+        // so they are compared with how they were to tell if they have changed
+        let panel_document = self.panel_document.clone();
+
         let mut command = None;
 
         egui_macroquad::ui(|egui_ctx| {
@@ -112,21 +283,104 @@ impl TopPanel {
                 .insert(egui::TextStyle::Body, (egui::FontFamily::Proportional, 19.));
             egui_ctx.set_fonts(fonts);
 
-            let top_panel_command =
-                self.top_panel(egui_ctx, fa, configurations, can_undo, can_redo);
+            let top_panel_command = self.top_panel(
+                egui_ctx,
+                model,
+                configurations,
+                &tab_names,
+                current_document,
+                can_undo,
+                can_redo,
+                unsaved_changes,
+            );
             if let Some(top_panel_command) = top_panel_command {
                 command = Some(top_panel_command);
             }
 
             if let Mode::MultipleRun = self.mode {
-                self.left_panel(egui_ctx, fa);
+                self.left_panel(egui_ctx, model);
             } else {
                 self.width = 0.;
             }
 
+            // These windows are shown over any kind of document
+            if self.discarding_action.is_some() {
+                let unsaved_changes_command =
+                    self.show_unsaved_changes_window(egui_ctx, unsaved_changes);
+                if let Some(unsaved_changes_command) = unsaved_changes_command {
+                    command = Some(unsaved_changes_command);
+                }
+            }
+
+            if self.file_browser.purpose.is_some() {
+                self.show_file_browser(egui_ctx);
+            }
+
+            if let Some(file_error) = self.file_error.clone() {
+                self.show_file_error_window(egui_ctx, file_error);
+            }
+
+            if let Some(comparison_error) = self.comparison_error {
+                self.show_comparison_error_window(egui_ctx, comparison_error);
+            }
+
+            if let Some(conversion_error) = self.conversion_error {
+                self.show_conversion_error_window(egui_ctx, conversion_error);
+            }
+
+            if let Some(jff_error) = self.jff_error.clone() {
+                self.show_jff_error_window(egui_ctx, jff_error);
+            }
+
+            if !self.jff_warnings.is_empty() {
+                self.show_jff_warnings_window(egui_ctx);
+            }
+
+            match self.panel_document {
+                Some(PanelDocument::Regex(_)) => {
+                    self.regex_editor(egui_ctx);
+                    return;
+                }
+                Some(PanelDocument::Grammar(_)) => {
+                    match self.mode {
+                        Mode::LlParse => self.ll_parse_panel(egui_ctx),
+                        Mode::SlrParse => self.slr_parse_panel(egui_ctx),
+                        _ => {}
+                    }
+
+                    self.grammar_editor(egui_ctx);
+
+                    if self.cyk_input_window.open {
+                        self.show_cyk_input_window(egui_ctx);
+                    }
+
+                    if self.cyk_table.is_some() {
+                        self.show_cyk_window(egui_ctx);
+                    }
+
+                    if self.parse_tree.is_some() {
+                        self.show_parse_tree_window(egui_ctx);
+                    }
+
+                    if self.transformation_steps.is_some() {
+                        self.show_transform_window(egui_ctx);
+                    }
+
+                    if self.greibach_conversion.is_some() {
+                        self.show_greibach_window(egui_ctx);
+                    }
+
+                    if self.brute_force_open {
+                        self.show_brute_force_window(egui_ctx);
+                    }
+                    return;
+                }
+                None => {}
+            }
+
             let context_menu_command = self.context_menu(
                 egui_ctx,
-                fa,
+                model,
                 states,
                 mouse_position,
                 selected_state,
@@ -138,14 +392,14 @@ impl TopPanel {
             }
 
             if self.simulate_input_window.open {
-                let simulate_input_command = self.show_simulate_input_window(egui_ctx, fa);
+                let simulate_input_command = self.show_simulate_input_window(egui_ctx, model);
                 if let Some(simulate_input_command) = simulate_input_command {
                     command = Some(simulate_input_command);
                 }
             }
 
             if self.fast_run_input_window.open {
-                self.show_fast_run_input_window(egui_ctx, fa);
+                self.show_fast_run_input_window(egui_ctx, model);
             }
 
             if let Some(fast_run_result) = self.fast_run_result {
@@ -158,31 +412,65 @@ impl TopPanel {
                     command = Some(set_name_input_command);
                 }
             }
+
+            if self.set_output_input_window.open {
+                let set_output_input_command = self.show_set_output_input_window(egui_ctx, model);
+                if let Some(set_output_input_command) = set_output_input_command {
+                    command = Some(set_output_input_command);
+                }
+            }
+
+            if self.minimize_partition.is_some() {
+                self.show_minimize_window(egui_ctx, states);
+            }
+
+            if let Some(equivalence_result) = self.equivalence_result.clone() {
+                self.show_equivalence_result_window(egui_ctx, equivalence_result);
+            }
+
+            if self.state_elimination.is_some() {
+                self.show_state_elimination_window(egui_ctx, states);
+            }
         });
 
+        if self.panel_document != panel_document {
+            documents[current_document].command_handler.mark_dirty();
+        }
+
         command
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn top_panel(
         &mut self,
         egui_ctx: &egui::CtxRef,
-        fa: &FiniteAutomaton,
-        configurations: &mut Vec<FiniteAutomatonConfiguration>,
+        model: &Model,
+        configurations: &Configurations,
+        tab_names: &[String],
+        current_document: usize,
         can_undo: bool,
         can_redo: bool,
+        unsaved_changes: bool,
     ) -> Option<TopPanelCommand> {
         let mut command = None;
 
         egui::TopBottomPanel::top("top_panel").show(egui_ctx, |ui| {
-            let menu_bar_command = self.menu_bar(ui, can_undo, can_redo);
+            let menu_bar_command = self.menu_bar(ui, model, can_undo, can_redo, unsaved_changes);
             if let Some(menu_bar_command) = menu_bar_command {
                 command = Some(menu_bar_command);
             }
 
+            ui.separator();
+
+            let tab_bar_command = self.tab_bar(ui, tab_names, current_document, unsaved_changes);
+            if let Some(tab_bar_command) = tab_bar_command {
+                command = Some(tab_bar_command);
+            }
+
             if let Mode::Simulate = self.mode {
                 ui.separator();
 
-                let simulation_toolbar_command = self.simulation_toolbar(ui, fa, configurations);
+                let simulation_toolbar_command = self.simulation_toolbar(ui, model, configurations);
                 if let Some(simulation_toolbar_command) = simulation_toolbar_command {
                     command = Some(simulation_toolbar_command);
                 }
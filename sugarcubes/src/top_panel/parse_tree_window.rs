@@ -0,0 +1,82 @@
+use super::TopPanel;
+use sugarcubes_core::grammar::{is_variable, ParseTree, EMPTY_STRING_SYMBOL};
+
+// The horizontal space given to each leaf, and the vertical space between levels
+const LEAF_SPACING: f32 = 40.;
+const LEVEL_SPACING: f32 = 50.;
+const NODE_RADIUS: f32 = 14.;
+
+impl TopPanel {
+    pub(super) fn show_parse_tree_window(&mut self, egui_ctx: &egui::CtxRef) {
+        let tree = match &self.parse_tree {
+            Some(tree) => tree,
+            None => return,
+        };
+
+        let mut window_open = true;
+        let response = egui::Window::new("Parse Tree")
+            .open(&mut window_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                // Leave an extra level for variables deriving the empty string
+                let size = egui::vec2(
+                    tree.width() as f32 * LEAF_SPACING,
+                    (tree.depth() + 1) as f32 * LEVEL_SPACING,
+                );
+                let (response, painter) = ui.allocate_painter(size, egui::Sense::hover());
+                draw_subtree(&painter, ui.visuals(), tree, response.rect.left_top());
+            });
+
+        if let Some(inner_response) = response {
+            self.contains_mouse |= inner_response.response.hovered();
+        }
+
+        if !window_open {
+            self.parse_tree = None;
+        }
+    }
+}
+
+// Draw a tree with its top left corner at the given point, returning the position of its root.
+// Each node is centered over the leaves beneath it.
+fn draw_subtree(
+    painter: &egui::Painter,
+    visuals: &egui::Visuals,
+    tree: &ParseTree,
+    corner: egui::Pos2,
+) -> egui::Pos2 {
+    let position = egui::pos2(
+        corner.x + tree.width() as f32 * LEAF_SPACING / 2.,
+        corner.y + LEVEL_SPACING / 2.,
+    );
+    let stroke = visuals.widgets.noninteractive.fg_stroke;
+
+    let mut child_corner = corner + egui::vec2(0., LEVEL_SPACING);
+    for child in &tree.children {
+        let child_position = draw_subtree(painter, visuals, child, child_corner);
+        painter.line_segment([position, child_position], stroke);
+        child_corner.x += child.width() as f32 * LEAF_SPACING;
+    }
+
+    // A variable with no children derives the empty string
+    if is_variable(tree.symbol) && tree.children.is_empty() {
+        let empty_position = position + egui::vec2(0., LEVEL_SPACING);
+        painter.line_segment([position, empty_position], stroke);
+        draw_node(painter, visuals, EMPTY_STRING_SYMBOL, empty_position);
+    }
+
+    draw_node(painter, visuals, tree.symbol, position);
+    position
+}
+
+fn draw_node(painter: &egui::Painter, visuals: &egui::Visuals, symbol: char, position: egui::Pos2) {
+    painter.circle_filled(position, NODE_RADIUS, visuals.extreme_bg_color);
+    painter.text(
+        position,
+        egui::Align2::CENTER_CENTER,
+        symbol,
+        egui::TextStyle::Monospace,
+        visuals.text_color(),
+    );
+}
@@ -0,0 +1,59 @@
+use super::{PanelDocument, TopPanel, ACCEPT_COLOR, REJECT_COLOR};
+use crate::{DocumentCommand, DOCUMENT_COMMAND_BUFFER};
+use sugarcubes_core::regex::Regex;
+
+impl TopPanel {
+    // Show the regular expression document in place of the automaton canvas
+    pub(super) fn regex_editor(&mut self, egui_ctx: &egui::CtxRef) {
+        let regex = match &mut self.panel_document {
+            Some(PanelDocument::Regex(regex)) => regex,
+            _ => return,
+        };
+
+        egui::CentralPanel::default().show(egui_ctx, |ui| {
+            ui.heading("Regular Expression");
+            ui.label("Use + for union, * for Kleene star, and ! for the empty string");
+            ui.separator();
+
+            ui.add(
+                egui::TextEdit::singleline(regex)
+                    .text_style(egui::TextStyle::Monospace)
+                    .desired_width(f32::INFINITY),
+            );
+
+            if regex.trim().is_empty() {
+                return;
+            }
+
+            match regex.parse::<Regex>() {
+                Ok(parsed) => {
+                    ui.add(egui::Label::new("Valid expression").text_color(ACCEPT_COLOR));
+                    ui.label(format!("Simplified: {}", parsed));
+
+                    ui.separator();
+
+                    if ui.button("Convert to NFA").clicked() {
+                        DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                            if let Ok(mut buff) = buff.try_borrow_mut() {
+                                buff.push(DocumentCommand::ConvertRegexToNFA);
+                            }
+                        });
+                    }
+                }
+                Err(error) => {
+                    ui.add(egui::Label::new(error.to_string()).text_color(REJECT_COLOR));
+
+                    // Point at the offending character underneath a copy of the input
+                    ui.add(egui::Label::new(regex.as_str()).monospace());
+                    ui.add(
+                        egui::Label::new(format!("{}^", " ".repeat(error.position)))
+                            .monospace()
+                            .text_color(REJECT_COLOR),
+                    );
+                }
+            }
+        });
+
+        self.contains_mouse = true;
+    }
+}
@@ -0,0 +1,34 @@
+use super::{Command, TopPanel, TopPanelCommand};
+use crate::model::Model;
+
+impl TopPanel {
+    pub(super) fn show_set_output_input_window(
+        &mut self,
+        egui_ctx: &egui::CtxRef,
+        model: &Model,
+    ) -> Option<TopPanelCommand> {
+        let mut command = None;
+        let (hit_ok, contains_mouse) = self.set_output_input_window.show(egui_ctx);
+        self.contains_mouse |= contains_mouse;
+
+        if hit_ok {
+            self.set_output_input_window.open = false;
+            if let Some(set_output_state_id) = self.set_output_state_id {
+                if let Some(old_output) = model.state_output(set_output_state_id) {
+                    command = Some(TopPanelCommand::Command(Command::SetStateOutput(
+                        set_output_state_id,
+                        old_output,
+                        self.set_output_input_window.input.clone(),
+                    )));
+                }
+            }
+        }
+
+        if !self.set_output_input_window.open {
+            self.set_output_input_window.input.clear();
+            self.set_output_state_id = None;
+        }
+
+        command
+    }
+}
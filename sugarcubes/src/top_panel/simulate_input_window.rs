@@ -1,11 +1,11 @@
 use super::{Mode, TopPanel, TopPanelCommand};
-use sugarcubes_core::automata::{finite_automaton::FiniteAutomaton, SimulateAutomaton};
+use crate::model::Model;
 
 impl TopPanel {
     pub(super) fn show_simulate_input_window(
         &mut self,
         egui_ctx: &egui::CtxRef,
-        fa: &FiniteAutomaton,
+        model: &Model,
     ) -> Option<TopPanelCommand> {
         let mut command = None;
 
@@ -14,7 +14,8 @@ impl TopPanel {
         self.contains_mouse |= contains_mouse;
 
         if hit_ok {
-            new_configurations = Some(fa.initial_configurations(&self.simulate_input_window.input));
+            new_configurations =
+                Some(model.initial_configurations(&self.simulate_input_window.input));
             self.mode = Mode::Simulate;
             self.string_simulating = self.simulate_input_window.input.clone();
             self.simulate_input_window.open = false;
@@ -1,17 +1,18 @@
-use super::{Mode, TopPanel, TopPanelCommand};
-use sugarcubes_core::automata::{
-    finite_automaton::{FiniteAutomaton, FiniteAutomatonConfiguration},
-    Configuration, SimulateAutomaton, EMPTY_STRING,
-};
+use super::{Mode, TopPanel, TopPanelCommand, REJECT_COLOR};
+use crate::model::*;
 
+const CONFIGURATION_WIDTH: f32 = 75.;
 const CONFIGURATION_HEIGHT: f32 = 60.;
+// The approximate size of each character and extra line of a configuration, e.g. a tape
+const CHARACTER_WIDTH: f32 = 14.;
+const LINE_HEIGHT: f32 = 26.;
 
 impl TopPanel {
     pub(super) fn simulation_toolbar(
         &mut self,
         ui: &mut egui::Ui,
-        fa: &FiniteAutomaton,
-        configurations: &mut Vec<FiniteAutomatonConfiguration>,
+        model: &Model,
+        configurations: &Configurations,
     ) -> Option<TopPanelCommand> {
         let mut command = None;
 
@@ -30,7 +31,7 @@ impl TopPanel {
 
                 ui.horizontal(|ui| {
                     ui.set_min_height(CONFIGURATION_HEIGHT);
-                    self.list_configurations(ui, fa, configurations);
+                    self.list_configurations(ui, model, configurations);
                 });
 
                 ui.separator();
@@ -42,9 +43,17 @@ impl TopPanel {
 
                     if ui.button("Reset").clicked() {
                         command = Some(TopPanelCommand::StartSimulation(
-                            fa.initial_configurations(&self.string_simulating),
+                            model.initial_configurations(&self.string_simulating),
                         ))
                     }
+
+                    // Dropped configurations may have gone on to accept
+                    if configurations.is_truncated() {
+                        ui.add(
+                            egui::Label::new("Some configurations were dropped at the limit")
+                                .text_color(REJECT_COLOR),
+                        );
+                    }
                 });
             });
         });
@@ -55,48 +64,63 @@ impl TopPanel {
     fn list_configurations(
         &mut self,
         ui: &mut egui::Ui,
-        fa: &FiniteAutomaton,
-        configurations: &mut Vec<FiniteAutomatonConfiguration>,
+        model: &Model,
+        configurations: &Configurations,
     ) {
-        for configuration in configurations {
-            let config_exhausted = configuration.remaining_string.is_empty()
-                && !fa
-                    .automaton
-                    .transitions_from(configuration.state())
-                    .into_iter()
-                    .any(|&transition| transition.symbol() == EMPTY_STRING);
-
-            let (fill, text_color, message) = if config_exhausted {
-                if fa.automaton.is_final(configuration.state()) {
-                    (
-                        Some(egui::Color32::from_rgb(122, 240, 98)),
-                        egui::Color32::BLACK,
-                        "accept",
-                    )
-                } else {
-                    (Some(egui::Color32::RED), egui::Color32::WHITE, "reject")
-                }
-            } else {
-                (
-                    None,
+        for view in configurations.views(model) {
+            let (fill, text_color, status) = match view.status {
+                ConfigurationStatus::Accepted => (
+                    Some(egui::Color32::from_rgb(122, 240, 98)),
+                    egui::Color32::BLACK,
+                    Some("accept"),
+                ),
+                ConfigurationStatus::Rejected => (
+                    Some(egui::Color32::RED),
                     egui::Color32::WHITE,
-                    configuration.remaining_string.as_str(),
-                )
+                    Some("reject"),
+                ),
+                ConfigurationStatus::Running => (None, egui::Color32::WHITE, None),
             };
 
-            let mut button = egui::Button::new(format!(
-                "{}\n{}",
-                configuration.state().to_string(),
-                message,
-            ))
-            .text_color(text_color)
-            .text_style(egui::TextStyle::Heading);
+            // A stopped configuration shows its result above anything left to show,
+            // such as the tapes of a Turing machine
+            let lines: Vec<&str> = match status {
+                Some(status) => std::iter::once(status)
+                    .chain(
+                        view.lines
+                            .iter()
+                            .map(String::as_str)
+                            .filter(|line| !line.is_empty()),
+                    )
+                    .collect(),
+                None => view.lines.iter().map(String::as_str).collect(),
+            };
+
+            let longest_line = lines
+                .iter()
+                .map(|line| line.chars().count())
+                .max()
+                .unwrap_or(0);
+            let size = [
+                CONFIGURATION_WIDTH.max(longest_line as f32 * CHARACTER_WIDTH),
+                CONFIGURATION_HEIGHT + (lines.len().max(1) - 1) as f32 * LINE_HEIGHT,
+            ];
+
+            let mut button = egui::Button::new(format!("{}\n{}", view.state, lines.join("\n")))
+                .text_color(text_color)
+                .text_style(egui::TextStyle::Heading);
 
             if let Some(fill) = fill {
                 button = button.fill(fill);
             }
 
-            if ui.add_sized([75., CONFIGURATION_HEIGHT], button).clicked() {}
+            // Machines with output show their output tape below the configuration
+            ui.vertical(|ui| {
+                ui.add_sized(size, button);
+                if let Some(output) = &view.output {
+                    ui.add(egui::Label::new(format!("Output: {}", output)).monospace());
+                }
+            });
         }
     }
 }
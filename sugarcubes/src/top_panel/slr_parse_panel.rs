@@ -0,0 +1,187 @@
+use super::{Mode, PanelDocument, TopPanel, ACCEPT_COLOR, REJECT_COLOR};
+use crate::{DocumentCommand, DOCUMENT_COMMAND_BUFFER};
+use sugarcubes_core::grammar::{SlrConflict, SlrParseAction, SlrParseTable};
+
+impl TopPanel {
+    // Show the LR(0) item sets and SLR(1) parse table of the grammar,
+    // and trace parsing a string with it
+    pub(super) fn slr_parse_panel(&mut self, egui_ctx: &egui::CtxRef) {
+        let grammar = match &self.panel_document {
+            Some(PanelDocument::Grammar(grammar)) => grammar,
+            _ => return,
+        };
+
+        // Rebuild the table whenever the grammar is edited, discarding the old trace
+        let table_outdated = self
+            .slr_parse_table
+            .as_ref()
+            .is_none_or(|table| table.grammar() != grammar);
+        if table_outdated {
+            self.slr_parse_table = SlrParseTable::new(grammar);
+            self.slr_parse_trace.clear();
+            self.slr_parse_shown_steps = 0;
+        }
+
+        let table = &self.slr_parse_table;
+        let mode = &mut self.mode;
+        let input = &mut self.slr_parse_input;
+        let trace = &mut self.slr_parse_trace;
+        let shown_steps = &mut self.slr_parse_shown_steps;
+
+        egui::SidePanel::right("slr_parse")
+            .resizable(false)
+            .show(egui_ctx, |ui| {
+                // Title bar and close button
+                ui.horizontal(|ui| {
+                    ui.with_layout(egui::Layout::left_to_right(), |ui| {
+                        ui.heading("SLR(1) Parse");
+                    });
+                    ui.with_layout(egui::Layout::right_to_left(), |ui| {
+                        if ui.button("X").clicked() {
+                            *mode = Mode::Edit;
+                        }
+                    });
+                });
+
+                ui.separator();
+
+                let table = match table {
+                    Some(table) => table,
+                    None => {
+                        ui.label("Requires a context-free grammar");
+                        return;
+                    }
+                };
+
+                egui::ScrollArea::auto_sized().show(ui, |ui| {
+                    ui.collapsing("Productions", |ui| {
+                        for (i, production) in table.productions().iter().enumerate() {
+                            ui.add(egui::Label::new(format!("{}: {}", i, production)).monospace());
+                        }
+                    });
+
+                    ui.collapsing("LR(0) Item Sets", |ui| {
+                        for (i, items) in table.item_sets().iter().enumerate() {
+                            ui.add(egui::Label::new(format!("I{}", i)).strong());
+                            for &item in items {
+                                ui.add(egui::Label::new(table.item_text(item)).monospace());
+                            }
+                        }
+                    });
+
+                    if ui.button("Open Item Set DFA").clicked() {
+                        DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                            if let Ok(mut buff) = buff.try_borrow_mut() {
+                                buff.push(DocumentCommand::OpenItemAutomaton);
+                            }
+                        });
+                    }
+
+                    ui.separator();
+
+                    let conflicts = table.conflicts();
+                    parse_table_grid(ui, table, &conflicts);
+                    if !conflicts.is_empty() {
+                        for conflict in &conflicts {
+                            ui.add(egui::Label::new(conflict.to_string()).text_color(REJECT_COLOR));
+                        }
+                        return;
+                    }
+                    ui.separator();
+
+                    ui.horizontal(|ui| {
+                        ui.label("Input:");
+                        ui.text_edit_singleline(input);
+                    });
+                    ui.horizontal(|ui| {
+                        if ui.button("Start").clicked() {
+                            *trace = table.parse(input).unwrap_or_default();
+                            *shown_steps = 1;
+                        }
+
+                        let can_step = *shown_steps < trace.len();
+                        let step_button = egui::widgets::Button::new("Step").enabled(can_step);
+                        if ui.add(step_button).clicked() {
+                            *shown_steps += 1;
+                        }
+                        let complete_button =
+                            egui::widgets::Button::new("Complete").enabled(can_step);
+                        if ui.add(complete_button).clicked() {
+                            *shown_steps = trace.len();
+                        }
+                    });
+
+                    let shown_trace = &trace[..*shown_steps];
+                    if !shown_trace.is_empty() {
+                        egui::Grid::new("slr_parse_trace")
+                            .striped(true)
+                            .show(ui, |ui| {
+                                ui.add(egui::Label::new("Stack").strong());
+                                ui.add(egui::Label::new("Input").strong());
+                                ui.add(egui::Label::new("Action").strong());
+                                ui.end_row();
+
+                                for step in shown_trace {
+                                    ui.add(egui::Label::new(&step.stack).monospace());
+                                    ui.add(egui::Label::new(&step.remaining).monospace());
+                                    let action = egui::Label::new(step.action.to_string());
+                                    ui.add(match step.action {
+                                        SlrParseAction::Accept => action.text_color(ACCEPT_COLOR),
+                                        SlrParseAction::Reject => action.text_color(REJECT_COLOR),
+                                        _ => action,
+                                    });
+                                    ui.end_row();
+                                }
+                            });
+                    }
+                });
+            });
+
+        self.contains_mouse = true;
+    }
+}
+
+// One row per state, with the actions on each lookahead followed by the goto on each variable,
+// and conflicting cells highlighted
+fn parse_table_grid(ui: &mut egui::Ui, table: &SlrParseTable, conflicts: &[SlrConflict]) {
+    egui::Grid::new("slr_parse_table")
+        .striped(true)
+        .show(ui, |ui| {
+            let lookaheads = table.lookaheads();
+            let variables = table.variables();
+            ui.label("");
+            for symbol in lookaheads.iter().chain(&variables) {
+                ui.add(egui::Label::new(symbol.to_string()).monospace().strong());
+            }
+            ui.end_row();
+
+            for state in 0..table.item_sets().len() {
+                ui.add(egui::Label::new(state.to_string()).monospace().strong());
+                for &lookahead in &lookaheads {
+                    let text = table
+                        .actions(state, lookahead)
+                        .iter()
+                        .map(|action| action.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ");
+                    let label = egui::Label::new(text).monospace();
+                    if conflicts
+                        .iter()
+                        .any(|conflict| conflict.cell() == (state, lookahead))
+                    {
+                        ui.add(label.text_color(REJECT_COLOR));
+                    } else {
+                        ui.add(label);
+                    }
+                }
+                for &variable in &variables {
+                    let text = table
+                        .goto(state, variable)
+                        .map(|next| next.to_string())
+                        .unwrap_or_default();
+                    ui.add(egui::Label::new(text).monospace());
+                }
+                ui.end_row();
+            }
+        });
+}
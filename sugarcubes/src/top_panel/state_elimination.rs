@@ -0,0 +1,103 @@
+use super::TopPanel;
+use crate::{DocumentCommand, States, DOCUMENT_COMMAND_BUFFER};
+use sugarcubes_core::regex::EliminationOrder;
+
+impl TopPanel {
+    pub(super) fn show_state_elimination_window(
+        &mut self,
+        egui_ctx: &egui::CtxRef,
+        states: &mut States,
+    ) {
+        let gnfa = match &mut self.state_elimination {
+            Some(gnfa) => gnfa,
+            None => return,
+        };
+
+        let mut window_open = true;
+        let mut finished = false;
+        let response = egui::Window::new("Convert to Regular Expression")
+            .open(&mut window_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                let (initial, accept) = (gnfa.initial(), gnfa.accept());
+                let mut name = |state: u32| {
+                    if state == initial {
+                        "start".to_string()
+                    } else if state == accept {
+                        "end".to_string()
+                    } else {
+                        states.get_name(state)
+                    }
+                };
+
+                if let Some(regex) = gnfa.to_regex() {
+                    ui.label("All states have been removed, giving the expression:");
+                    ui.add(egui::Label::new(regex.to_string()).monospace());
+                    if ui.button("Open as Regular Expression").clicked() {
+                        DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                            if let Ok(mut buff) = buff.try_borrow_mut() {
+                                buff.push(DocumentCommand::NewRegex(regex.to_string()));
+                            }
+                        });
+                        finished = true;
+                    }
+                } else {
+                    ui.label("Choose a state to remove next:");
+
+                    let mut removed = None;
+                    ui.horizontal_wrapped(|ui| {
+                        for &state in gnfa.remaining_states() {
+                            if ui.button(name(state)).clicked() {
+                                removed = Some(state);
+                            }
+                        }
+                    });
+
+                    ui.horizontal(|ui| {
+                        if ui.button("Remove Next").clicked() {
+                            removed = gnfa.next_state(&EliminationOrder::FewestPaths);
+                        }
+
+                        if ui.button("Remove All").clicked() {
+                            while let Some(state) = gnfa.next_state(&EliminationOrder::FewestPaths)
+                            {
+                                gnfa.eliminate(state);
+                            }
+                        }
+                    });
+
+                    if let Some(removed) = removed {
+                        gnfa.eliminate(removed);
+                    }
+                }
+
+                ui.separator();
+
+                // The current label of every transition, as a regular expression
+                egui::Grid::new("state_elimination_labels")
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.add(egui::Label::new("From").strong());
+                        ui.add(egui::Label::new("To").strong());
+                        ui.add(egui::Label::new("Label").strong());
+                        ui.end_row();
+
+                        for (&(from, to), label) in gnfa.labels() {
+                            ui.label(name(from));
+                            ui.label(name(to));
+                            ui.add(egui::Label::new(label.to_string()).monospace());
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        if let Some(inner_response) = response {
+            self.contains_mouse |= inner_response.response.hovered();
+        }
+
+        if !window_open || finished {
+            self.state_elimination = None;
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use super::{unsaved_changes::DiscardingAction, TopPanel, TopPanelCommand};
+use crate::document::{document_name, Document};
+
+use std::path::PathBuf;
+
+#[cfg(target_arch = "wasm32")]
+use sapp_jsutils::JsObject;
+
+#[cfg(target_arch = "wasm32")]
+extern "C" {
+    fn set_page_title(title: JsObject);
+}
+
+impl TopPanel {
+    // Show a tab for each open document, named after its file and marked with an asterisk
+    // while it has unsaved changes. Only the current tab can be closed, so that the user
+    // is asked about the unsaved changes of the document they are looking at.
+    pub(super) fn tab_bar(
+        &mut self,
+        ui: &mut egui::Ui,
+        tab_names: &[String],
+        current_document: usize,
+        unsaved_changes: bool,
+    ) -> Option<TopPanelCommand> {
+        let mut command = None;
+
+        ui.horizontal(|ui| {
+            for (index, name) in tab_names.iter().enumerate() {
+                if index == current_document {
+                    let _ = ui.selectable_label(true, name);
+                    if ui.small_button("x").clicked() {
+                        command =
+                            self.discard_document(DiscardingAction::CloseTab, unsaved_changes);
+                    }
+                } else if ui.selectable_label(false, name).clicked() {
+                    command = Some(TopPanelCommand::SwitchTab(index));
+                }
+                ui.separator();
+            }
+
+            if ui.small_button("+").clicked() {
+                command = Some(TopPanelCommand::NewTab);
+            }
+        });
+
+        command
+    }
+
+    pub(super) fn tab_names(&self, documents: &[Document], current_document: usize) -> Vec<String> {
+        documents
+            .iter()
+            .enumerate()
+            .map(|(index, document)| {
+                // The current document's file is held by the top panel
+                let name = if index == current_document {
+                    self.document_name()
+                } else {
+                    document_name(document.file_path.as_deref())
+                };
+                if document.command_handler.is_dirty() {
+                    format!("{}*", name)
+                } else {
+                    name
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the name of the document's file, or "Untitled" if it has none
+    pub fn document_name(&self) -> String {
+        document_name(self.file_path.as_deref())
+    }
+
+    /// Remembers the file the document was opened from or saved to,
+    /// showing its name as the page title on the web
+    pub fn set_file_path(&mut self, path: Option<PathBuf>) {
+        self.file_path = path;
+        #[cfg(target_arch = "wasm32")]
+        unsafe {
+            set_page_title(JsObject::string(&self.document_name()));
+        }
+    }
+
+    /// Takes over the parts of the document edited in the top panel as its tab is opened,
+    /// closing any windows left open for the previous document
+    pub fn load_document(&mut self, document: &mut Document) {
+        self.panel_document = document.panel_document.take();
+        self.set_file_path(document.file_path.take());
+        self.mode = document.mode;
+        self.close_document_windows();
+    }
+
+    /// Hands back the parts of the document edited in the top panel as another tab is opened
+    pub fn store_document(&mut self, document: &mut Document) {
+        document.panel_document = self.panel_document.take();
+        document.file_path = self.file_path.take();
+        document.mode = self.mode;
+    }
+
+    // Close the windows which show or change the current document
+    fn close_document_windows(&mut self) {
+        self.open_context_menu = false;
+
+        self.simulate_input_window.open = false;
+        self.fast_run_input_window.open = false;
+        self.fast_run_result = None;
+        for pair in self.multiple_run_strings.iter_mut() {
+            pair.1 = None;
+        }
+        self.multiple_run_selected_index = None;
+
+        self.set_name_input_window.open = false;
+        self.set_name_state_id = None;
+        self.set_output_input_window.open = false;
+        self.set_output_state_id = None;
+
+        self.minimize_partition = None;
+        self.equivalence_result = None;
+        self.comparison_error = None;
+        self.conversion_error = None;
+        self.state_elimination = None;
+
+        self.discarding_action = None;
+        self.saving_before_discard = false;
+        self.file_browser.purpose = None;
+
+        self.cyk_input_window.open = false;
+        self.cyk_table = None;
+        self.parse_tree = None;
+        self.transformation_steps = None;
+        self.greibach_conversion = None;
+        self.brute_force_open = false;
+        self.brute_force_result = None;
+    }
+}
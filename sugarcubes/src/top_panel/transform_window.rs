@@ -0,0 +1,80 @@
+use super::{PanelDocument, TopPanel, REJECT_COLOR};
+
+impl TopPanel {
+    // Walk through the intermediate grammars of a transformation, one step at a time
+    pub(super) fn show_transform_window(&mut self, egui_ctx: &egui::CtxRef) {
+        let steps = match &self.transformation_steps {
+            Some(steps) => steps,
+            None => return,
+        };
+        let index = &mut self.transformation_index;
+
+        let mut window_open = true;
+        let mut chosen_grammar = None;
+        let response = egui::Window::new("Transform Grammar")
+            .open(&mut window_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                let steps = match steps {
+                    Ok(steps) => steps,
+                    Err(error) => {
+                        ui.add(egui::Label::new(error.to_string()).text_color(REJECT_COLOR));
+                        return;
+                    }
+                };
+                let step = &steps[*index];
+
+                ui.heading(format!(
+                    "Step {} of {}: {}",
+                    *index + 1,
+                    steps.len(),
+                    step.kind
+                ));
+                ui.separator();
+
+                egui::ScrollArea::auto_sized().show(ui, |ui| {
+                    if step.grammar.productions.is_empty() {
+                        ui.label("The grammar generates no strings");
+                    }
+                    for production in &step.grammar.productions {
+                        ui.add(egui::Label::new(production.to_string()).monospace());
+                    }
+                });
+
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    let previous_button =
+                        egui::widgets::Button::new("Previous").enabled(*index > 0);
+                    if ui.add(previous_button).clicked() {
+                        *index -= 1;
+                    }
+
+                    let next_button =
+                        egui::widgets::Button::new("Next").enabled(*index + 1 < steps.len());
+                    if ui.add(next_button).clicked() {
+                        *index += 1;
+                    }
+
+                    if ui.button("Use This Grammar").clicked() {
+                        chosen_grammar = Some(step.grammar.clone());
+                    }
+                });
+            });
+
+        if let Some(inner_response) = response {
+            self.contains_mouse |= inner_response.response.hovered();
+        }
+
+        if let Some(grammar) = chosen_grammar {
+            self.panel_document = Some(PanelDocument::Grammar(grammar));
+            window_open = false;
+        }
+
+        if !window_open {
+            self.transformation_steps = None;
+            self.transformation_index = 0;
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use super::{file_browser::FileBrowserPurpose, TopPanel, TopPanelCommand};
+use crate::{DocumentCommand, DOCUMENT_COMMAND_BUFFER};
+
+/// Something the user asked for which would throw away the current document
+pub enum DiscardingAction {
+    // Replace the document, such as with a new one
+    Document(DocumentCommand),
+    // Choose a file to open in place of the document
+    Open,
+    // Close the document's tab
+    CloseTab,
+    // Close every tab, asking about each one's unsaved changes in turn, and then Sugarcubes
+    Quit,
+}
+
+impl TopPanel {
+    /// Performs the action straight away if the document has no unsaved changes,
+    /// and otherwise asks the user whether to save them first
+    pub fn discard_document(
+        &mut self,
+        action: DiscardingAction,
+        unsaved_changes: bool,
+    ) -> Option<TopPanelCommand> {
+        if unsaved_changes {
+            self.discarding_action = Some(action);
+            self.saving_before_discard = false;
+            None
+        } else {
+            self.perform_discarding_action(action)
+        }
+    }
+
+    fn perform_discarding_action(&mut self, action: DiscardingAction) -> Option<TopPanelCommand> {
+        match action {
+            DiscardingAction::Document(command) => DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                if let Ok(mut buff) = buff.try_borrow_mut() {
+                    buff.push(command);
+                }
+            }),
+            DiscardingAction::Open => self.choose_file(FileBrowserPurpose::Open),
+            DiscardingAction::CloseTab => return Some(TopPanelCommand::CloseTab),
+            DiscardingAction::Quit => return Some(TopPanelCommand::Quit),
+        }
+        None
+    }
+
+    pub(super) fn show_unsaved_changes_window(
+        &mut self,
+        egui_ctx: &egui::CtxRef,
+        unsaved_changes: bool,
+    ) -> Option<TopPanelCommand> {
+        // Saving finishes on a later frame, after which the action goes ahead if it worked.
+        // A failed save is reported in its own window, and closing the "Save As" browser
+        // without choosing a file gives up on the action.
+        if self.saving_before_discard {
+            if !unsaved_changes {
+                self.saving_before_discard = false;
+                return self
+                    .discarding_action
+                    .take()
+                    .and_then(|action| self.perform_discarding_action(action));
+            } else if self.file_error.is_some() || self.file_browser.purpose.is_none() {
+                self.saving_before_discard = false;
+                self.discarding_action = None;
+            }
+            return None;
+        }
+
+        let mut save = false;
+        let mut discard = false;
+        let mut window_open = true;
+        let response = egui::Window::new("Unsaved Changes")
+            .open(&mut window_open)
+            .resizable(false)
+            .collapsible(false)
+            .show(egui_ctx, |ui| {
+                ui.label(format!(
+                    "Save the changes to {} first?",
+                    self.document_name()
+                ));
+
+                ui.horizontal(|ui| {
+                    save = ui.button("Save").clicked();
+                    discard = ui.button("Discard").clicked();
+                    if ui.button("Cancel").clicked() {
+                        self.discarding_action = None;
+                    }
+                });
+            });
+        if !window_open {
+            self.discarding_action = None;
+        }
+
+        if let Some(inner_response) = response {
+            self.contains_mouse |= inner_response.response.hovered();
+        }
+
+        if discard {
+            return self
+                .discarding_action
+                .take()
+                .and_then(|action| self.perform_discarding_action(action));
+        } else if save {
+            self.saving_before_discard = true;
+            self.file_error = None;
+            if self.file_path.is_some() {
+                DOCUMENT_COMMAND_BUFFER.with(|buff| {
+                    if let Ok(mut buff) = buff.try_borrow_mut() {
+                        buff.push(DocumentCommand::SaveJFF);
+                    }
+                });
+            } else {
+                self.choose_file(FileBrowserPurpose::SaveAs);
+            }
+        }
+
+        None
+    }
+}
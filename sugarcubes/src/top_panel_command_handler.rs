@@ -1,10 +1,13 @@
-use crate::{command::Command, states::States};
-
-use sugarcubes_core::automata::finite_automaton::FiniteAutomaton;
+use crate::{command::Command, model::Model, states::States};
 
 pub struct TopPanelCommandHandler {
     undo_stack: Vec<Command>,
     redo_stack: Vec<Command>,
+    // Whether the document has changed since it was created, opened or last saved
+    dirty: bool,
+    // Counts every edit, undo, redo and replacement of the model, so that anything showing
+    // an earlier version of it can tell that it has changed
+    revision: u64,
 }
 
 impl TopPanelCommandHandler {
@@ -12,26 +15,43 @@ impl TopPanelCommandHandler {
         Self {
             undo_stack: Vec::new(),
             redo_stack: Vec::new(),
+            dirty: false,
+            revision: 0,
         }
     }
 
-    pub fn execute(&mut self, command: Command, fa: &mut FiniteAutomaton, states: &mut States) {
-        command.execute(fa, states);
+    /// Forgets the history of a model which has been replaced by another,
+    /// counting the replacement as a change
+    pub fn reset(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.dirty = false;
+        self.revision += 1;
+    }
+
+    pub fn execute(&mut self, command: Command, model: &mut Model, states: &mut States) {
+        command.execute(model, states);
         self.undo_stack.push(command);
         self.redo_stack.clear();
+        self.dirty = true;
+        self.revision += 1;
     }
 
-    pub fn undo(&mut self, fa: &mut FiniteAutomaton, states: &mut States) {
+    pub fn undo(&mut self, model: &mut Model, states: &mut States) {
         if let Some(command) = self.undo_stack.pop() {
-            command.undo(fa, states);
+            command.undo(model, states);
             self.redo_stack.push(command);
+            self.dirty = true;
+            self.revision += 1;
         }
     }
 
-    pub fn redo(&mut self, fa: &mut FiniteAutomaton, states: &mut States) {
+    pub fn redo(&mut self, model: &mut Model, states: &mut States) {
         if let Some(command) = self.redo_stack.pop() {
-            command.execute(fa, states);
+            command.execute(model, states);
             self.undo_stack.push(command);
+            self.dirty = true;
+            self.revision += 1;
         }
     }
 
@@ -42,4 +62,24 @@ impl TopPanelCommandHandler {
     pub fn can_redo(&self) -> bool {
         !self.redo_stack.is_empty()
     }
+
+    /// Returns a number which changes whenever the model does
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Whether the document has unsaved changes
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Records a change made outside of a command, such as moving a state
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Records that the document matches its file, or has only just been created
+    pub fn mark_clean(&mut self) {
+        self.dirty = false;
+    }
 }